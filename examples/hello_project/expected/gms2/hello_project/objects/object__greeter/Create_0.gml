@@ -0,0 +1 @@
+greeter__on_create();
@@ -0,0 +1,38 @@
+{
+    "id": "efb71f29-5b9a-567c-b3eb-8a7052b0f436",
+    "modelName": "GMObject",
+    "mvc": "1.0",
+    "name": "object__greeter",
+    "eventList": [
+        {
+            "id": "d24a8b5c-591c-5e9a-baf1-ccf2c44814f3",
+            "modelName": "GMEvent",
+            "mvc": "1.0",
+            "IsDnD": false,
+            "collisionObjectId": "00000000-0000-0000-0000-000000000000",
+            "enumb": 0,
+            "eventtype": 0,
+            "m_owner": "efb71f29-5b9a-567c-b3eb-8a7052b0f436"
+        }
+    ],
+    "maskSpriteId": "00000000-0000-0000-0000-000000000000",
+    "overriddenProperties": null,
+    "parentObjectId": "00000000-0000-0000-0000-000000000000",
+    "persistent": false,
+    "physicsAngularDamping": 0.1,
+    "physicsDensity": 0.5,
+    "physicsFriction": 0.2,
+    "physicsGroup": 0,
+    "physicsKinematic": false,
+    "physicsLinearDamping": 0.1,
+    "physicsObject": false,
+    "physicsRestitution": 0.1,
+    "physicsSensor": false,
+    "physicsShape": 1,
+    "physicsShapePoints": null,
+    "physicsStartAwake": true,
+    "properties": null,
+    "solid": false,
+    "spriteId": "11caea55-2f0f-5944-9ee1-8887853630f4",
+    "visible": true
+}
\ No newline at end of file
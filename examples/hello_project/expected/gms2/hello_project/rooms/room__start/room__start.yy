@@ -0,0 +1,290 @@
+{
+    "name": "room__start",
+    "id": "c810a67c-c2e1-5ae6-bb39-d61a686cf673",
+    "creationCodeFile": "",
+    "inheritCode": false,
+    "inheritCreationOrder": false,
+    "inheritLayers": false,
+    "instanceCreationOrderIDs": [
+        "7ba8f6f1-996a-5840-9d5b-2a3dbebbbcf1"
+    ],
+    "IsDnD": false,
+    "layers": [
+        {
+            "__type": "GMRInstanceLayer_Model:#YoYoStudio.MVCFormat",
+            "name": "Instances",
+            "id": "a2300c5e-f41d-5904-8aea-667cc04fd678",
+            "depth": 0,
+            "grid_x": 32,
+            "grid_y": 32,
+            "hierarchyFrozen": false,
+            "hierarchyVisible": true,
+            "inheritLayerDepth": false,
+            "inheritLayerSettings": false,
+            "inheritSubLayers": false,
+            "inheritVisibility": false,
+            "instances": [
+                {
+                    "name": "inst_7BA8F6F1",
+                    "id": "7ba8f6f1-996a-5840-9d5b-2a3dbebbbcf1",
+                    "colour": {
+                        "Value": 4294967295
+                    },
+                    "creationCodeFile": "",
+                    "creationCodeType": "",
+                    "ignore": false,
+                    "inheritCode": false,
+                    "inheritItemSettings": false,
+                    "IsDnD": false,
+                    "m_originalParentID": "00000000-0000-0000-0000-000000000000",
+                    "m_serialiseFrozen": false,
+                    "modelName": "GMRInstance",
+                    "name_with_no_file_rename": "inst_7BA8F6F1",
+                    "objId": "efb71f29-5b9a-567c-b3eb-8a7052b0f436",
+                    "properties": null,
+                    "rotation": 0,
+                    "scaleX": 1,
+                    "scaleY": 1,
+                    "mvc": "1.0",
+                    "x": 160,
+                    "y": 120
+                }
+            ],
+            "layers": [],
+            "m_parentID": "00000000-0000-0000-0000-000000000000",
+            "m_serialiseFrozen": false,
+            "modelName": "GMRInstanceLayer",
+            "mvc": "1.0",
+            "userdefined_depth": false,
+            "visible": true
+        },
+        {
+            "__type": "GMRBackgroundLayer_Model:#YoYoStudio.MVCFormat",
+            "name": "Background",
+            "id": "f592c399-24d5-56a8-a84a-6a69d380da27",
+            "animationFPS": 15,
+            "animationSpeedType": "0",
+            "colour": {
+                "Value": 4290822336
+            },
+            "depth": 16000,
+            "grid_x": 32,
+            "grid_y": 32,
+            "hierarchyFrozen": false,
+            "hierarchyVisible": true,
+            "hspeed": 0,
+            "htiled": false,
+            "inheritLayerDepth": false,
+            "inheritLayerSettings": false,
+            "inheritSubLayers": false,
+            "inheritVisibility": false,
+            "layers": [],
+            "m_parentID": "00000000-0000-0000-0000-000000000000",
+            "m_serialiseFrozen": false,
+            "modelName": "GMRBackgroundLayer",
+            "mvc": "1.0",
+            "spriteId": "00000000-0000-0000-0000-000000000000",
+            "stretch": false,
+            "userdefined_animFPS": false,
+            "userdefined_depth": false,
+            "visible": true,
+            "vspeed": 0,
+            "vtiled": false,
+            "x": 0,
+            "y": 0
+        }
+    ],
+    "modelName": "GMRoom",
+    "parentId": "00000000-0000-0000-0000-000000000000",
+    "physicsSettings": {
+        "id": "6be742d8-e364-5857-89c7-62b1b44dcea1",
+        "inheritPhysicsSettings": false,
+        "modelName": "GMRoomPhysicsSettings",
+        "PhysicsWorld": false,
+        "PhysicsWorldGravityX": 0,
+        "PhysicsWorldGravityY": 10,
+        "PhysicsWorldPixToMeters": 0.1,
+        "mvc": "1.0"
+    },
+    "roomSettings": {
+        "id": "7d3721cf-4edd-5bbb-972c-aa993e02f1ff",
+        "Height": 240,
+        "inheritRoomSettings": false,
+        "modelName": "GMRoomSettings",
+        "persistent": false,
+        "mvc": "1.0",
+        "Width": 320
+    },
+    "mvc": "1.0",
+    "views": [
+        {
+            "id": "c1a3d069-74f7-5af1-a5e3-6b1fa080bcf0",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "fa3cf08f-e224-5f8d-b736-2cb39b1a0736",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "aff93010-ee59-526c-8623-78130cca2219",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "3d9b6431-9cc5-5762-8359-c092e4225e30",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "e887f6db-5bb7-5fad-99c0-3a691eb9023a",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "86a4e4e0-436c-57b2-9d1b-1c7af9c7c4aa",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "ba444af8-32ef-5552-9543-fe3681d5d6a0",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        },
+        {
+            "id": "c843f092-796e-5fe6-b1bb-16401976439e",
+            "hborder": 32,
+            "hport": 768,
+            "hspeed": -1,
+            "hview": 768,
+            "inherit": false,
+            "modelName": "GMRView",
+            "objId": "00000000-0000-0000-0000-000000000000",
+            "mvc": "1.0",
+            "vborder": 32,
+            "visible": false,
+            "vspeed": -1,
+            "wport": 1024,
+            "wview": 1024,
+            "xport": 0,
+            "xview": 0,
+            "yport": 0,
+            "yview": 0
+        }
+    ],
+    "viewSettings": {
+        "id": "1b4b8389-6af3-5718-afd3-c73ad96b3deb",
+        "clearDisplayBuffer": false,
+        "clearViewBackground": false,
+        "enableViews": false,
+        "inheritViewSettings": false,
+        "modelName": "GMRoomViewSettings",
+        "mvc": "1.0"
+    }
+}
\ No newline at end of file
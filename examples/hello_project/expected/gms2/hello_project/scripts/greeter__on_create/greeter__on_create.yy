@@ -0,0 +1,8 @@
+{
+    "id": "21ce45e6-230f-5031-b62f-f8b346c644b8",
+    "modelName": "GMScript",
+    "mvc": "1.0",
+    "name": "greeter__on_create",
+    "IsCompatibility": false,
+    "IsDnD": false
+}
\ No newline at end of file
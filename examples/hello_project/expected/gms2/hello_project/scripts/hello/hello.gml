@@ -0,0 +1,2 @@
+var s = argument0;
+print("hello" + s);
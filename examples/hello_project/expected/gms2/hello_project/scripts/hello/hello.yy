@@ -0,0 +1,8 @@
+{
+    "id": "9efbc47c-6739-53b1-85a0-d29f59654036",
+    "modelName": "GMScript",
+    "mvc": "1.0",
+    "name": "hello",
+    "IsCompatibility": false,
+    "IsDnD": false
+}
\ No newline at end of file
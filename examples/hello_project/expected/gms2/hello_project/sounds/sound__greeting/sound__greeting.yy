@@ -0,0 +1,14 @@
+{
+    "id": "ec86f7b3-443d-55d2-857b-49415c798e2b",
+    "modelName": "GMSound",
+    "mvc": "1.0",
+    "name": "sound__greeting",
+    "audioGroupGuid": "7b2c4976-1e09-44e5-8256-c527145e03bb",
+    "bitDepth": 1,
+    "bitRate": 192,
+    "kind": 0,
+    "preload": false,
+    "sampleRate": 44100,
+    "type": 0,
+    "volume": 1
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+{
+    "id": "11caea55-2f0f-5944-9ee1-8887853630f4",
+    "modelName": "GMSprite",
+    "mvc": "1.12",
+    "name": "sprite__greeter",
+    "For3D": false,
+    "HTile": false,
+    "VTile": false,
+    "bbox_bottom": 0,
+    "bbox_left": 0,
+    "bbox_right": 0,
+    "bbox_top": 0,
+    "bboxmode": 0,
+    "colkind": 0,
+    "coltolerance": 0,
+    "edgeFiltering": false,
+    "frames": [
+        {
+            "id": "fecefe9e-c1a5-58c8-bf2d-0fd2d5f5b4d6",
+            "modelName": "GMSpriteFrame",
+            "mvc": "1.0",
+            "SpriteId": "11caea55-2f0f-5944-9ee1-8887853630f4",
+            "compositeImage": {
+                "id": "3eb76d25-7a16-5474-8d18-9ea2a307c67a",
+                "modelName": "GMSpriteImage",
+                "mvc": "1.0",
+                "FrameId": "fecefe9e-c1a5-58c8-bf2d-0fd2d5f5b4d6",
+                "LayerId": "00000000-0000-0000-0000-000000000000"
+            },
+            "images": [
+                {
+                    "id": "f05b7834-4602-5639-8963-0784586c1dd3",
+                    "modelName": "GMSpriteImage",
+                    "mvc": "1.0",
+                    "FrameId": "fecefe9e-c1a5-58c8-bf2d-0fd2d5f5b4d6",
+                    "LayerId": "106becc1-2e58-5022-ac0f-31117f61eaa0"
+                }
+            ]
+        }
+    ],
+    "gridX": 0,
+    "gridY": 0,
+    "height": 16,
+    "layers": [
+        {
+            "id": "106becc1-2e58-5022-ac0f-31117f61eaa0",
+            "modelName": "GMImageLayer",
+            "mvc": "1.0",
+            "SpriteId": "11caea55-2f0f-5944-9ee1-8887853630f4",
+            "blendMode": 0,
+            "isLocked": false,
+            "name": "default",
+            "opacity": 100,
+            "visible": true
+        }
+    ],
+    "origin": 4,
+    "originLocked": false,
+    "playbackSpeed": 15,
+    "playbackSpeedType": 0,
+    "premultiplyAlpha": false,
+    "sepmasks": false,
+    "swatchColours": null,
+    "swfPrecision": 2.525,
+    "textureGroupId": "1225f6b0-ac20-43bd-a82e-be73fa0b6f4f",
+    "type": 0,
+    "width": 16,
+    "xorig": 8,
+    "yorig": 8
+}
\ No newline at end of file
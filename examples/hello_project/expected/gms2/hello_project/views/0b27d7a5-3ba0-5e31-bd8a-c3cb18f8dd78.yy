@@ -0,0 +1,13 @@
+{
+    "id": "0b27d7a5-3ba0-5e31-bd8a-c3cb18f8dd78",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "0b27d7a5-3ba0-5e31-bd8a-c3cb18f8dd78",
+    "children": [
+        "96d3a78d-6618-51ba-a102-74e486216fd7"
+    ],
+    "filterType": "GMObject",
+    "folderName": "objects",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Objects"
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+{
+    "id": "20b2e672-6931-5c20-b5d3-7e487d2b4a87",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "20b2e672-6931-5c20-b5d3-7e487d2b4a87",
+    "children": [
+        "11caea55-2f0f-5944-9ee1-8887853630f4"
+    ],
+    "filterType": "GMSprite",
+    "folderName": "greeter",
+    "isDefaultView": false,
+    "localisedFolderName": ""
+}
\ No newline at end of file
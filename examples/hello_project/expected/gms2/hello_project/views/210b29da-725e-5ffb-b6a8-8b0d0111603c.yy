@@ -0,0 +1,13 @@
+{
+    "id": "210b29da-725e-5ffb-b6a8-8b0d0111603c",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "210b29da-725e-5ffb-b6a8-8b0d0111603c",
+    "children": [
+        "20b2e672-6931-5c20-b5d3-7e487d2b4a87"
+    ],
+    "filterType": "GMSprite",
+    "folderName": "sprites",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Sprites"
+}
\ No newline at end of file
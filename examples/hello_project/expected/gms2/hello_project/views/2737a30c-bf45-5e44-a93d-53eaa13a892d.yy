@@ -0,0 +1,11 @@
+{
+    "id": "2737a30c-bf45-5e44-a93d-53eaa13a892d",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "2737a30c-bf45-5e44-a93d-53eaa13a892d",
+    "children": [],
+    "filterType": "GMTileSet",
+    "folderName": "tilesets",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Tilesets"
+}
\ No newline at end of file
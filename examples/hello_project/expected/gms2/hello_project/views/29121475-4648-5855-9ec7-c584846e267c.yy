@@ -0,0 +1,13 @@
+{
+    "id": "29121475-4648-5855-9ec7-c584846e267c",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "29121475-4648-5855-9ec7-c584846e267c",
+    "children": [
+        "ec86f7b3-443d-55d2-857b-49415c798e2b"
+    ],
+    "filterType": "GMSound",
+    "folderName": "greeter",
+    "isDefaultView": false,
+    "localisedFolderName": ""
+}
\ No newline at end of file
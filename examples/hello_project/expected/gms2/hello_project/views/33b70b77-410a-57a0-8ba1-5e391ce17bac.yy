@@ -0,0 +1,14 @@
+{
+    "id": "33b70b77-410a-57a0-8ba1-5e391ce17bac",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "33b70b77-410a-57a0-8ba1-5e391ce17bac",
+    "children": [
+        "a2567379-a61e-5a32-b07b-0233f62bbc0e",
+        "9efbc47c-6739-53b1-85a0-d29f59654036"
+    ],
+    "filterType": "GMScript",
+    "folderName": "scripts",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Scripts"
+}
\ No newline at end of file
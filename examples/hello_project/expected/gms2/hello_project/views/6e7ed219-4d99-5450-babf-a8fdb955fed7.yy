@@ -0,0 +1,11 @@
+{
+    "id": "6e7ed219-4d99-5450-babf-a8fdb955fed7",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "6e7ed219-4d99-5450-babf-a8fdb955fed7",
+    "children": [],
+    "filterType": "GMFont",
+    "folderName": "fonts",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Fonts"
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+{
+    "id": "7126edc8-7601-5c60-9ba8-6e3c91cb897d",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "7126edc8-7601-5c60-9ba8-6e3c91cb897d",
+    "children": [
+        "c810a67c-c2e1-5ae6-bb39-d61a686cf673"
+    ],
+    "filterType": "GMRoom",
+    "folderName": "greeter",
+    "isDefaultView": false,
+    "localisedFolderName": ""
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+{
+    "id": "8ae89302-39f2-5900-874e-36c995ece325",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "8ae89302-39f2-5900-874e-36c995ece325",
+    "children": [
+        "29121475-4648-5855-9ec7-c584846e267c"
+    ],
+    "filterType": "GMSound",
+    "folderName": "sounds",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Sounds"
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+{
+    "id": "96d3a78d-6618-51ba-a102-74e486216fd7",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "96d3a78d-6618-51ba-a102-74e486216fd7",
+    "children": [
+        "efb71f29-5b9a-567c-b3eb-8a7052b0f436"
+    ],
+    "filterType": "GMObject",
+    "folderName": "greeter",
+    "isDefaultView": false,
+    "localisedFolderName": ""
+}
\ No newline at end of file
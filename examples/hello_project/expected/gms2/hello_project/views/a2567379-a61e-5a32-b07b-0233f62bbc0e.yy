@@ -0,0 +1,13 @@
+{
+    "id": "a2567379-a61e-5a32-b07b-0233f62bbc0e",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "a2567379-a61e-5a32-b07b-0233f62bbc0e",
+    "children": [
+        "21ce45e6-230f-5031-b62f-f8b346c644b8"
+    ],
+    "filterType": "GMScript",
+    "folderName": "greeter",
+    "isDefaultView": false,
+    "localisedFolderName": ""
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+{
+    "id": "d90770d6-508f-52ad-96cc-bc2620e9f04d",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "d90770d6-508f-52ad-96cc-bc2620e9f04d",
+    "children": [],
+    "filterType": "GMPath",
+    "folderName": "paths",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Paths"
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+{
+    "id": "f3fb88f3-d8bc-5c8c-af6c-d6138bb10736",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "f3fb88f3-d8bc-5c8c-af6c-d6138bb10736",
+    "children": [
+        "7126edc8-7601-5c60-9ba8-6e3c91cb897d"
+    ],
+    "filterType": "GMRoom",
+    "folderName": "rooms",
+    "isDefaultView": false,
+    "localisedFolderName": "ResourceTree_Rooms"
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+{
+    "id": "fac18184-eaff-5d31-9a10-59dd55bb08ed",
+    "modelName": "GMFolder",
+    "mvc": "1.1",
+    "name": "fac18184-eaff-5d31-9a10-59dd55bb08ed",
+    "children": [
+        "210b29da-725e-5ffb-b6a8-8b0d0111603c",
+        "2737a30c-bf45-5e44-a93d-53eaa13a892d",
+        "8ae89302-39f2-5900-874e-36c995ece325",
+        "d90770d6-508f-52ad-96cc-bc2620e9f04d",
+        "33b70b77-410a-57a0-8ba1-5e391ce17bac",
+        "6e7ed219-4d99-5450-babf-a8fdb955fed7",
+        "0b27d7a5-3ba0-5e31-bd8a-c3cb18f8dd78",
+        "f3fb88f3-d8bc-5c8c-af6c-d6138bb10736"
+    ],
+    "filterType": "root",
+    "folderName": "Default",
+    "isDefaultView": true,
+    "localisedFolderName": ""
+}
\ No newline at end of file
@@ -148,11 +148,11 @@ fn impl_parse_key_values(fields: &FieldsNamed) -> TokenStream {
   // Assemble Method
 
   quote! {
-    fn parse_key_value(&mut self, #source_ast: &T, #key: &Key, #value: &IExpr) -> #module::Result<()> {
-      match #key.name_of().as_ref() {
+    fn parse_key_value(&mut self, #source_ast: &T, #key: &IKey, #value: &IExpr) -> #module::Result<()> {
+      match #key.content.name_of().as_ref() {
         #(#matches),*,
         field => {
-          return #module::MglError::invalid_field(field, #module::InvalidFieldKind::NotFound)
+          return #module::MglError::invalid_field(field, #module::InvalidFieldKind::NotFound(#key.clone()))
         }
       }
       Ok(())
@@ -1,11 +1,46 @@
 use crate::ast::expressions::{Expression, IExpr, ResourceName};
 use crate::ast::statements::IStat;
+use crate::ast::operators::BinaryOp;
+use crate::ast::info::AstDebugInfo;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Declaration {
   Function(FunctionDeclaration),
   Resource(ResourceDeclaration),
   Instance(InstanceDeclaration),
+  Module(ModuleDeclaration),
+  Import(ImportDeclaration),
+  Constant(ConstantDeclaration),
+  Enum(EnumDeclaration),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleDeclaration {
+  pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportDeclaration {
+  pub path: IExpr,
+  pub alias: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantDeclaration {
+  pub name: String,
+  pub value: IExpr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDeclaration {
+  pub name: String,
+  pub variants: Vec<EnumVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant {
+  pub name: AstDebugInfo<String>,
+  pub value: Option<IExpr>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,12 +57,13 @@ pub struct ResourceDeclaration {
   pub name: String,
   pub methods: Vec<FunctionDeclaration>,
   pub instances: Vec<InstanceDeclaration>,
+  pub constants: Vec<ConstantDeclaration>,
   pub key_value_pairs: Vec<KeyValue>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct InstanceDeclaration {
-  pub object: ResourceName,
+  pub object: AstDebugInfo<ResourceName>,
   pub name: String,
   pub key_value_pairs: Vec<KeyValue>,
 }
@@ -36,14 +72,19 @@ pub struct InstanceDeclaration {
 pub enum ResourceKind {
   Room,
   Sound,
+  Font,
+  Path,
+  Background,
   Sprite,
   Object,
   Wrapper,
 }
 
+pub type IKey = AstDebugInfo<Key>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeyValue {
-  pub key:   Key,
+  pub key:   IKey,
   pub value: IExpr
 }
 
@@ -64,19 +105,99 @@ impl FunctionDeclaration {
       body: body
     }
   }
+
+  /// The names bound inside the function: its arguments, `var`s and `for` variables.
+  pub fn local_names(&self) -> Vec<&String> {
+    let mut names = self.args.iter().collect::<Vec<_>>();
+    names.extend(self.body.content.local_names());
+    names
+  }
+}
+
+impl ConstantDeclaration {
+  pub fn new(name: &str, value: IExpr) -> Self {
+    ConstantDeclaration {
+      name: String::from(name),
+      value,
+    }
+  }
+}
+
+impl EnumDeclaration {
+  pub fn new(name: &str, variants: &[EnumVariant]) -> Self {
+    EnumDeclaration {
+      name: String::from(name),
+      variants: Vec::from(variants),
+    }
+  }
+}
+
+impl EnumVariant {
+  pub fn new(name: AstDebugInfo<String>, value: Option<IExpr>) -> Self {
+    EnumVariant {
+      name,
+      value,
+    }
+  }
+
+  /// Without a value, a variant is one more than the variant before it, or 0 if it's the first.
+  pub fn value_after(&self, previous: Option<&EnumVariant>) -> IExpr {
+    match (&self.value, previous) {
+      (Some(value), _) => value.clone(),
+
+      (None, Some(previous)) => {
+        let previous = AstDebugInfo::with_location_of(Expression::Name(previous.name.content_clone()), &self.name);
+        let one      = AstDebugInfo::with_location_of(Expression::num("1"), &self.name);
+        AstDebugInfo::with_location_of(Expression::binary_op(BinaryOp::Add, previous, one), &self.name)
+      }
+
+      (None, None) => AstDebugInfo::with_location_of(Expression::num("0"), &self.name),
+    }
+  }
+}
+
+impl ModuleDeclaration {
+  pub fn new(names: &[&str]) -> Self {
+    ModuleDeclaration {
+      names: names.iter().map(|x| String::from(*x)).collect(),
+    }
+  }
+}
+
+impl ImportDeclaration {
+  /// Without an alias, the import is known by the last name of its path.
+  pub fn new(path: IExpr, alias: Option<&str>) -> Self {
+    let alias = match (alias, path.content_clone()) {
+      (Some(alias), _) => String::from(alias),
+      (None, Expression::Resource(name)) => name.last().clone(),
+      _ => unreachable!()
+    };
+
+    ImportDeclaration {
+      path,
+      alias,
+    }
+  }
+
+  pub fn resource_name(&self) -> ResourceName {
+    match self.path.content_clone() {
+      Expression::Resource(name) => name,
+      _ => unreachable!()
+    }
+  }
 }
 
 impl InstanceDeclaration {
   pub fn new(object_expression: IExpr, name: &str, keyvals: &[KeyValue]) -> Self {
     let object_name;
-    match object_expression.content() {
+    match object_expression.content_clone() {
       Expression::Resource(name) => { object_name = name },
       Expression::Name(name)     => { object_name = ResourceName::Name(name) }
       _ => unreachable!()
     }
     InstanceDeclaration {
       name:            String::from(name),
-      object:          object_name,
+      object:          AstDebugInfo::with_location_of(object_name, &object_expression),
       key_value_pairs: Vec::from(keyvals)
     }
   }
@@ -94,9 +215,17 @@ impl ResourceDeclaration {
       name:            String::from(name),
       methods:         Vec::from(methods),
       instances:       Vec::from(instances),
+      constants:       Vec::new(),
       key_value_pairs: Vec::from(keyvals),
     }
   }
+
+  pub fn with_constants(self, constants: &[ConstantDeclaration]) -> Self {
+    ResourceDeclaration {
+      constants: Vec::from(constants),
+      ..self
+    }
+  }
 }
 
 impl ResourceKind {
@@ -105,11 +234,15 @@ impl ResourceKind {
 
     String::from(
       match self {
-        Object   => "object",
-        Wrapper  => "wrapper",
-        Sprite   => "sprite",
-        Sound    => "sound",
-        Room     => "room",
+        Object     => "object",
+        // wrappers are objects made of other objects, see `resources::wrapper`
+        Wrapper    => "object",
+        Sprite     => "sprite",
+        Sound      => "sound",
+        Font       => "font",
+        Path       => "path",
+        Background => "background",
+        Room       => "room",
       },
     )
   }
@@ -176,9 +309,10 @@ impl Key {
 }
 
 impl KeyValue {
+  #[allow(dead_code)]
   pub fn new(key: Key, value: IExpr) -> KeyValue {
     KeyValue {
-      key,
+      key: IKey::new(key),
       value
     }
   }
@@ -16,6 +16,8 @@ pub enum Expression {
   TernaryOp(IExpr, IExpr, IExpr),
   Call(IExpr, Vec<IExpr>),
   Indexing(IExpr, Accessor, Vec<IExpr>),
+  Array(Vec<IExpr>),
+  Map(Vec<(IExpr, IExpr)>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -51,6 +53,14 @@ impl Expression {
     Expression::Resource(ResourceName::new(names))
   }
 
+  /// The expression a resource name is written as, `a` or `a::b`.
+  pub fn from_resource_name(name: &ResourceName) -> Self {
+    match name {
+      ResourceName::Name(name) => Expression::Name(name.clone()),
+      _ => Expression::Resource(name.clone()),
+    }
+  }
+
   pub fn unary_op<T: Into<UnaryOp>>(op: T, e: IExpr) -> Self {
     Expression::UnaryOp(op.into(), e)
   }
@@ -70,6 +80,36 @@ impl Expression {
   pub fn indexing<T: Into<Accessor>>(value: IExpr, op: T, keys: &[IExpr]) -> Self {
     Expression::Indexing(value, op.into(), Vec::from(keys))
   }
+
+  pub fn array(values: &[IExpr]) -> Self {
+    Expression::Array(Vec::from(values))
+  }
+
+  pub fn map(entries: &[(IExpr, IExpr)]) -> Self {
+    Expression::Map(Vec::from(entries))
+  }
+
+  /// The expressions this one is made of, not including theirs.
+  pub fn subexpressions(&self) -> Vec<&IExpr> {
+    use Expression::*;
+
+    match self {
+      Str(_) | Num(_) | Bool(_) | Name(_) | Resource(_) => vec![],
+
+      Parentheses(e) | UnaryOp(_, e) => vec![e],
+      BinaryOp(_, a, b)              => vec![a, b],
+      TernaryOp(condition, a, b)     => vec![condition, a, b],
+      Array(values)                  => values.iter().collect(),
+
+      Call(e, others) | Indexing(e, _, others) => {
+        let mut expressions = vec![e];
+        expressions.extend(others);
+        expressions
+      }
+
+      Map(entries) => entries.iter().flat_map(|(key, value)| vec![key, value]).collect(),
+    }
+  }
 }
 
 impl ResourceName {
@@ -91,5 +131,44 @@ impl ResourceName {
       ResourceName::InModule(module, _) => &*module == name
     }
   }
-}
 
+  pub fn qualified(&self, module: &str) -> ResourceName {
+    if self.top_module_is(module) {
+      self.clone()
+    } else {
+      ResourceName::InModule(String::from(module), box self.clone())
+    }
+  }
+
+  pub fn names(&self) -> Vec<String> {
+    match self {
+      ResourceName::Name(name) => vec![name.clone()],
+      ResourceName::InModule(module, box rest) => {
+        let mut names = rest.names();
+        names.insert(0, module.clone());
+        names
+      }
+    }
+  }
+
+  pub fn last(&self) -> &String {
+    match self {
+      ResourceName::Name(name) => name,
+      ResourceName::InModule(_, box rest) => rest.last(),
+    }
+  }
+
+  /// `sprite::player` joined with `idle` is `sprite::player::idle`
+  pub fn join(&self, rest: &ResourceName) -> ResourceName {
+    match self {
+      ResourceName::Name(name) => ResourceName::InModule(name.clone(), box rest.clone()),
+      ResourceName::InModule(module, box next) => {
+        ResourceName::InModule(module.clone(), box next.join(rest))
+      }
+    }
+  }
+
+  pub fn starts_with(&self, prefix: &ResourceName) -> bool {
+    self.names().starts_with(&prefix.names())
+  }
+}
@@ -37,6 +37,18 @@ impl<T: Clone> AstDebugInfo<T> {
     new
   }
 
+  /// Something else found at the same place.
+  pub fn with_location_of<U>(content: T, other: &AstDebugInfo<U>) -> Self {
+    AstDebugInfo {
+      content: box content,
+      line_start: other.line_start,
+      line_end: other.line_end,
+      column_start: other.column_start,
+      column_end: other.column_end,
+      file: other.file,
+    }
+  }
+
   pub fn with_info(self, tk: Tokens) -> Self {
     let mut new = self.clone();
     let span  = tk.as_span();
@@ -11,8 +11,16 @@ pub enum BinaryOp {
   Sub,
   Mul,
   Div,
+  Mod,
+  IntDiv,
+  BitAnd,
+  BitOr,
+  BitXor,
+  Shl,
+  Shr,
   Or,
   And,
+  Xor,
   Lt,
   Gt,
   Geq,
@@ -27,6 +35,12 @@ pub enum UnaryOp {
   Not
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncrementOp {
+  Increment,
+  Decrement
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Accessor {
   None,
@@ -90,8 +104,17 @@ impl Operator for BinaryOp {
       "-"  => Sub,
       "*"  => Mul,
       "/"  => Div,
+      "%"  => Mod,
+      "mod" => Mod,
+      "div" => IntDiv,
+      "&"  => BitAnd,
+      "|"  => BitOr,
+      "^"  => BitXor,
+      "<<" => Shl,
+      ">>" => Shr,
       "||" => Or,
       "&&" => And,
+      "^^" => Xor,
       "<"  => Lt,
       ">"  => Gt,
       ">=" => Geq,
@@ -111,8 +134,16 @@ impl Operator for BinaryOp {
       Sub  => "-",
       Mul  => "*",
       Div  => "/",
+      Mod  => "%",
+      IntDiv => "div",
+      BitAnd => "&",
+      BitOr  => "|",
+      BitXor => "^",
+      Shl  => "<<",
+      Shr  => ">>",
       Or   => "||",
       And  => "&&",
+      Xor  => "^^",
       Lt   => "<",
       Gt   => ">",
       Geq  => ">=",
@@ -125,20 +156,29 @@ impl Operator for BinaryOp {
   fn priority(self) -> i64 {
     use BinaryOp::*;
 
+    // as in GML, bitwise operators bind tighter than comparisons
     match self {
-      Dot   => 1,
-      Mul   => 2,
-      Div   => 2,
-      Add   => 3,
-      Sub   => 3,
-      Lt    => 4,
-      Gt    => 4,
-      Geq   => 4,
-      Leq   => 4,
-      Eq    => 4,
-      Diff  => 4,
-      And   => 5,
-      Or    => 6,
+      Dot    => 1,
+      Mul    => 2,
+      Div    => 2,
+      Mod    => 2,
+      IntDiv => 2,
+      Add    => 3,
+      Sub    => 3,
+      Shl    => 4,
+      Shr    => 4,
+      BitAnd => 5,
+      BitXor => 6,
+      BitOr  => 7,
+      Lt     => 8,
+      Gt     => 8,
+      Geq    => 8,
+      Leq    => 8,
+      Eq     => 8,
+      Diff   => 8,
+      And    => 9,
+      Xor    => 10,
+      Or     => 11,
     }
   }
 }
@@ -146,6 +186,33 @@ impl Operator for BinaryOp {
 
 implement_into_operator_for_str!(BinaryOp);
 
+impl Operator for IncrementOp {
+  fn from_str(s: &str) -> Self {
+    use IncrementOp::*;
+
+    match s {
+      "++" => Increment,
+      "--" => Decrement,
+      _ => unreachable!()
+    }
+  }
+
+  fn as_str(&self) -> &'static str {
+    use IncrementOp::*;
+
+    match self {
+      Increment => "++",
+      Decrement => "--",
+    }
+  }
+
+  fn priority(self) -> i64 {
+    1
+  }
+}
+
+implement_into_operator_for_str!(IncrementOp);
+
 impl Operator for Accessor {
   fn from_str(s: &str) -> Self {
     use Accessor::*;
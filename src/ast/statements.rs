@@ -1,28 +1,44 @@
 use crate::ast::info::AstDebugInfo;
 use crate::ast::expressions::IExpr;
+use crate::ast::operators::*;
 
 pub type IStat = AstDebugInfo<Statement>;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Statement {
   Return(IExpr),
+  Break,
+  Continue,
+  Exit,
   Call(IExpr),
   Body(Vec<IStat>),
   With(IExpr, IStat),
   If(IExpr, IStat, Option<IStat>),
   While(IExpr, IStat),
+  Repeat(IExpr, IStat),
+  DoUntil(IStat, IExpr),
+  Switch(IExpr, Vec<SwitchCase>, Option<IStat>),
   For(String, ForRange, IStat),
   Assignment(IExpr, IExpr),
+  CompoundAssignment(BinaryOp, IExpr, IExpr),
+  Prefix(IncrementOp, IExpr),
+  Postfix(IExpr, IncrementOp),
   Var(Vec<VarDeclaration>),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ForRange {
   Array(IExpr),
   Integer(IExpr, IExpr, Option<IExpr>)
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchCase {
+  pub values: Vec<IExpr>,
+  pub body: IStat,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VarDeclaration {
   Assignment(String, IExpr),
   Name(String)
@@ -32,6 +48,106 @@ pub enum VarDeclaration {
 // Implementations
 
 impl Statement {
+  /// The expressions of the statement and of the statements inside it.
+  pub fn expressions(&self) -> Vec<&IExpr> {
+    use Statement::*;
+
+    match self {
+      Break | Continue | Exit => vec![],
+
+      Return(e) | Call(e) | Prefix(_, e) | Postfix(e, _) => vec![e],
+
+      Assignment(left, right) | CompoundAssignment(_, left, right) => vec![left, right],
+
+      Body(statements) => statements.iter().flat_map(|s| s.content.expressions()).collect(),
+
+      With(e, body) | While(e, body) | Repeat(e, body) | DoUntil(body, e) => {
+        let mut expressions = vec![e];
+        expressions.extend(body.content.expressions());
+        expressions
+      }
+
+      If(condition, then, or_else) => {
+        let mut expressions = vec![condition];
+        expressions.extend(then.content.expressions());
+        expressions.extend(or_else.iter().flat_map(|s| s.content.expressions()));
+        expressions
+      }
+
+      Switch(value, cases, default) => {
+        let mut expressions = vec![value];
+        for case in cases {
+          expressions.extend(&case.values);
+          expressions.extend(case.body.content.expressions());
+        }
+        expressions.extend(default.iter().flat_map(|s| s.content.expressions()));
+        expressions
+      }
+
+      For(_, range, body) => {
+        let mut expressions = match range {
+          ForRange::Array(array) => vec![array],
+          ForRange::Integer(from, to, by) => {
+            let mut expressions = vec![from, to];
+            expressions.extend(by);
+            expressions
+          }
+        };
+        expressions.extend(body.content.expressions());
+        expressions
+      }
+
+      Var(declarations) => {
+        declarations.iter().filter_map(|declaration| {
+          match declaration {
+            VarDeclaration::Assignment(_, value) => Some(value),
+            VarDeclaration::Name(_) => None,
+          }
+        }).collect()
+      }
+    }
+  }
+
+  /// The names declared by `var` and `for` in the statement and the statements inside it.
+  pub fn local_names(&self) -> Vec<&String> {
+    use Statement::*;
+
+    match self {
+      Return(_) | Break | Continue | Exit | Call(_) | Assignment(..) |
+      CompoundAssignment(..) | Prefix(..) | Postfix(..) => vec![],
+
+      Body(statements) => statements.iter().flat_map(|s| s.content.local_names()).collect(),
+
+      With(_, body) | While(_, body) | Repeat(_, body) | DoUntil(body, _) => body.content.local_names(),
+
+      If(_, then, or_else) => {
+        let mut names = then.content.local_names();
+        names.extend(or_else.iter().flat_map(|s| s.content.local_names()));
+        names
+      }
+
+      Switch(_, cases, default) => {
+        let mut names = cases.iter().flat_map(|case| case.body.content.local_names()).collect::<Vec<_>>();
+        names.extend(default.iter().flat_map(|s| s.content.local_names()));
+        names
+      }
+
+      For(name, _, body) => {
+        let mut names = vec![name];
+        names.extend(body.content.local_names());
+        names
+      }
+
+      Var(declarations) => {
+        declarations.iter().map(|declaration| {
+          match declaration {
+            VarDeclaration::Assignment(name, _) | VarDeclaration::Name(name) => name,
+          }
+        }).collect()
+      }
+    }
+  }
+
   pub fn return_op(e: IExpr) -> Self {
     Statement::Return(e)
   }
@@ -56,6 +172,18 @@ impl Statement {
     Statement::While(cond, then)
   }
 
+  pub fn repeat(times: IExpr, body: IStat) -> Self {
+    Statement::Repeat(times, body)
+  }
+
+  pub fn do_until(body: IStat, cond: IExpr) -> Self {
+    Statement::DoUntil(body, cond)
+  }
+
+  pub fn switch(value: IExpr, cases: &[SwitchCase], default: Option<IStat>) -> Self {
+    Statement::Switch(value, Vec::from(cases), default)
+  }
+
   pub fn for_op(name: &str, range: ForRange, then: IStat) -> Self {
     Statement::For(String::from(name), range, then)
   }
@@ -74,6 +202,18 @@ impl Statement {
     Statement::Assignment(left, right)
   }
 
+  pub fn compound_assignment<T: Into<BinaryOp>>(op: T, left: IExpr, right: IExpr) -> Self {
+    Statement::CompoundAssignment(op.into(), left, right)
+  }
+
+  pub fn prefix<T: Into<IncrementOp>>(op: T, e: IExpr) -> Self {
+    Statement::Prefix(op.into(), e)
+  }
+
+  pub fn postfix<T: Into<IncrementOp>>(e: IExpr, op: T) -> Self {
+    Statement::Postfix(e, op.into())
+  }
+
   pub fn var(d: &[VarDeclaration]) -> Self {
     Statement::Var(Vec::from(d))
   }
@@ -89,6 +229,15 @@ impl ForRange {
   }
 }
 
+impl SwitchCase {
+  pub fn new(values: &[IExpr], body: IStat) -> Self {
+    SwitchCase {
+      values: Vec::from(values),
+      body,
+    }
+  }
+}
+
 impl VarDeclaration {
   pub fn assignment(name: &str, e: IExpr) -> Self {
     VarDeclaration::Assignment(String::from(name), e)
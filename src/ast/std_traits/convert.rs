@@ -3,6 +3,7 @@ use crate::ast::std_traits::convert_macros::*;
 use crate::resources::sprite::*;
 use crate::resources::sound::*;
 use crate::resources::room::*;
+use crate::resources::object::*;
 use std::path::PathBuf;
 
 implement_match_try_from! {
@@ -13,13 +14,14 @@ implement_match_try_from! {
   ResourceName {
     Expression::Name(name)  => ResourceName::Name(name),
     Expression::Resource(r) => r
-  },
-
-  InstanceItem {
-    name => InstanceItem::Unresolved(ResourceName::try_from(name)?)
   }
 }
 
+try_from_common!(InstanceItem, |expr| {
+  let name = ResourceName::try_from(expr.clone())?;
+  Ok(InstanceItem::Unresolved(AstDebugInfo::with_location_of(name, &expr)))
+});
+
 
 implement_try_from_string_options! {
   CollisionKind {
@@ -33,6 +35,12 @@ implement_try_from_string_options! {
     "manual"     => BoundingBoxMode::Manual
   },
 
+  PhysicsShape {
+    "circle" => PhysicsShape::Circle,
+    "box"    => PhysicsShape::Box,
+    "convex" => PhysicsShape::Convex
+  },
+
   SoundKind {
     "normal"     => SoundKind::Normal,
     "background" => SoundKind::Background,
@@ -42,5 +50,5 @@ implement_try_from_string_options! {
 }
 
 implement_try_from_for_numbers!(u8, u32, u64, i32, i64, f32, f64, usize);
-implement_try_from_wrap_option!(PathBuf, ResourceName);
+implement_try_from_wrap_option!(PathBuf, ResourceName, PhysicsShape);
 
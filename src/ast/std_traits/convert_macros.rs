@@ -38,13 +38,19 @@ pub macro implement_try_from_for_numbers($($T: ty),+) {
     try_from_common!($T, |expr| {
       let value_type = format!("number ({})", stringify!($T));
 
-      match expr.as_ref() {
-        &Expression::Num(ref n) => {
-          match n.parse() {
-            Ok(n)  => Ok(n),
-            Err(_) => MglError::convert_expression(expr.clone(), &value_type)
-          }
+      let number = match expr.as_ref() {
+        Expression::Num(n) => Some(n.clone()),
+
+        // negative numbers are parsed as `-` applied to a number
+        Expression::UnaryOp(UnaryOp::Neg, n) => match n.as_ref() {
+          Expression::Num(n) => Some(format!("-{}", n)),
+          _ => None
         }
+        _ => None
+      };
+
+      match number.map(|n| n.parse()) {
+        Some(Ok(n)) => Ok(n),
         _ => MglError::convert_expression(expr.clone(), &value_type)
       }
     });
@@ -55,11 +61,11 @@ pub macro implement_try_from_string_options {
   ($($T: ty { $($option: expr => $value: expr),* }),+) => {
     $(
       try_from_common!($T, |expr| {
-        let string = String::try_from(expr)?;
+        let string = String::try_from(expr.clone())?;
 
         match &*string {
           $($option => Ok($value)),*,
-          _ => MglError::unknown_option(&*string, &[$($option),*])
+          _ => MglError::unknown_option_at(&expr, &*string, &[$($option),*])
         }
       });
     )*
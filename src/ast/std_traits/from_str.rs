@@ -38,6 +38,22 @@ implement_from_str! {
     Ok(parse_instance(parse_mgl(Rule::instance_declaration, s)?))
   },
 
+  ModuleDeclaration => {
+    Ok(parse_module(parse_mgl(Rule::module_declaration, s)?))
+  },
+
+  ImportDeclaration => {
+    Ok(parse_import(parse_mgl(Rule::import_declaration, s)?))
+  },
+
+  ConstantDeclaration => {
+    Ok(parse_constant(parse_mgl(Rule::constant_declaration, s)?))
+  },
+
+  EnumDeclaration => {
+    Ok(parse_enum(parse_mgl(Rule::enum_declaration, s)?))
+  },
+
   ResourceDeclaration => {
     if let Declaration::Resource(res) = s.parse()? {
       Ok(res)
@@ -1,15 +1,19 @@
 use std::env;
 use std::path::{Path, PathBuf};
 use clap::{App, AppSettings, ArgMatches, Arg, SubCommand, crate_version};
+use crate::compiler::export::{Target, TARGET_NAMES};
+use crate::manifest::PROJECT_FILE_NAME;
+use crate::error::{ErrorFormat, ERROR_FORMAT_NAMES};
 
 pub struct Command {
   pub action: Action,
   pub files: Vec<PathBuf>,
   pub project_file: Option<PathBuf>,
+  pub error_format: ErrorFormat,
 }
 
 pub enum Action {
-  Compile,
+  Compile(Option<PathBuf>, Option<Target>),
   ShowAst(bool),
   Project(bool),
   Scripts,
@@ -52,9 +56,29 @@ fn generate_app<'a, 'b>() -> App<'a, 'b> {
          .takes_value(true)
          .help("Select additional files as input"))
 
+    .arg(Arg::with_name("error-format")
+         .long("error-format")
+         .value_name("FORMAT")
+         .takes_value(true)
+         .possible_values(ERROR_FORMAT_NAMES)
+         .help("How to print errors, `json` prints one object per line (default: human)"))
+
 
     .subcommand(SubCommand::with_name("compile")
-                .about("Compile current project (still non-functional)"))
+                .about("Compile current project into a Game Maker project")
+                .arg(Arg::with_name("output")
+                     .short("o")
+                     .long("output")
+                     .value_name("DIRECTORY")
+                     .takes_value(true)
+                     .help("Where to write the project (default: build/ next to the project file)"))
+                .arg(Arg::with_name("target")
+                     .short("t")
+                     .long("target")
+                     .value_name("TARGET")
+                     .takes_value(true)
+                     .possible_values(TARGET_NAMES)
+                     .help("Game Maker version to compile to (default: project file's `target`, or gms1)")))
 
     .subcommand(SubCommand::with_name("ast")
                 .about("Show AST for all input files")
@@ -76,6 +100,7 @@ pub fn interpret_arguments() -> Command {
   Command {
     project_file: interpret_project_argument(&matches),
     files:        interpret_input_argument(&matches),
+    error_format: interpret_error_format(&matches),
     action:       interpret_subcommand(&matches),
   }
 }
@@ -83,7 +108,7 @@ pub fn interpret_arguments() -> Command {
 
 fn interpret_subcommand(matches: &ArgMatches) -> Action {
   match matches.subcommand() {
-    ("compile", _) => Action::Compile,
+    ("compile", m) => Action::Compile(interpret_output(m.unwrap()), interpret_target(m.unwrap())),
     ("project", m) => Action::Project(interpret_pretty(&m.unwrap())),
     ("ast",     m) => Action::ShowAst(interpret_pretty(&m.unwrap())),
     ("scripts", _) => Action::Scripts,
@@ -91,6 +116,18 @@ fn interpret_subcommand(matches: &ArgMatches) -> Action {
   }
 }
 
+fn interpret_output(matches: &ArgMatches) -> Option<PathBuf> {
+  matches.value_of("output").map(PathBuf::from)
+}
+
+fn interpret_target(matches: &ArgMatches) -> Option<Target> {
+  matches.value_of("target").map(|target| target.parse().unwrap())
+}
+
+fn interpret_error_format(matches: &ArgMatches) -> ErrorFormat {
+  matches.value_of("error-format").unwrap_or("human").parse().unwrap()
+}
+
 fn interpret_pretty(matches: &ArgMatches) -> bool {
   match matches.value_of("pretty").unwrap_or("yes") {
     "yes" | "true"  => true,
@@ -158,7 +195,7 @@ fn find_project_file() -> Option<PathBuf> {
 
 fn find_project_file_in(directory: &PathBuf) -> Option<PathBuf> {
   let mut path = directory.clone();
-  path.push("Project.mgl.toml");
+  path.push(PROJECT_FILE_NAME);
 
   if path.exists() {
     return Some(path)
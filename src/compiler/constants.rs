@@ -0,0 +1,442 @@
+//! Evaluates `const` declarations.
+//!
+//!   const TILE = 16
+//!   const ROOM_WIDTH = TILE * 20
+//!
+//!   object enemy {
+//!     const SPEED = TILE / 4
+//!   }
+//!
+//! A constant is known by its name in the module it was declared in and the
+//! modules below it, constants of a resource also in the methods of that
+//! resource. Anywhere else they are called by their full name, such as
+//! `const::enemy::SPEED`, or imported. Like aliases, constants don't take
+//! over the arguments, `var`s and `for` variables of a function, and a
+//! variable assigned to is never replaced by a constant of the same name.
+//!
+//! The variants of an enumeration are constants in a module named after it,
+//! known by `State::Walk` where `State` would be known.
+//!
+//!   enum State { Idle, Walk, Jump = 10 }
+//!
+//! Values are computed here and replace the uses of constants when scripts
+//! are written, see `compiler::script`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::file_reader::*;
+use crate::compiler::imports::file_module;
+use crate::resources::project::{Module, module_add, full_name_for};
+
+/// The value of each constant, by full name.
+pub type Constants = HashMap<ResourceName, IExpr>;
+
+pub fn evaluate_constants(tree: &AstFileTree) -> TopResult<Constants> {
+  let mut declarations = Declarations {
+    constants: HashMap::new(),
+    enums: HashSet::new(),
+    errors: Vec::new(),
+  };
+  collect_constants(tree, &mut declarations);
+  check_variants(tree, &declarations.constants, &declarations.enums, &mut declarations.errors);
+
+  let mut evaluator = Evaluator {
+    declared: &declarations.constants,
+    enums: &declarations.enums,
+    values: HashMap::new(),
+    path: Vec::new(),
+    errors: declarations.errors.clone(),
+  };
+
+  let mut names = declarations.constants.keys().collect::<Vec<_>>();
+  names.sort_by_key(|name| name.to_string());
+
+  for name in names {
+    evaluator.constant(name);
+  }
+
+  if evaluator.errors.is_empty() {
+    let values = evaluator.values.into_iter();
+    Ok(values.map(|(name, value)| (name, value.unwrap().expression())).collect())
+  } else {
+    Err(evaluator.errors)
+  }
+}
+
+/// The full name of the constant an expression refers to, from a script or
+/// constant declared in `module`.
+pub fn find_constant<T>(expression: &Expression,
+                        module:     &Module,
+                        constants:  &HashMap<ResourceName, T>) -> Option<ResourceName> {
+  let names = match expression {
+    Expression::Name(name)     => vec![name.clone()],
+    Expression::Resource(name) => name.names(),
+    _ => return None
+  };
+  candidates(&names, module).into_iter().find(|name| constants.contains_key(name))
+}
+
+/// `a::b` in the module `m` is the first of `const::m::a::b` and `const::a::b`
+/// that exists, `const::a::b` is only itself.
+fn candidates(names: &[String], module: &Module) -> Vec<ResourceName> {
+  if names[0] == "const" {
+    return vec![ResourceName::new(&names.iter().map(String::as_str).collect::<Vec<_>>())]
+  }
+
+  (0..=module.len()).rev().map(|length| {
+    let mut candidate = vec!["const"];
+    candidate.extend(module[..length].iter().map(String::as_str));
+    candidate.extend(names.iter().map(String::as_str));
+    ResourceName::new(&candidate)
+  }).collect()
+}
+
+/// The constant, and the module its value is computed in.
+type Declared = HashMap<ResourceName, (Module, IExpr)>;
+
+struct Declarations {
+  constants: Declared,
+
+  /// The full name of every enumeration, `const::m::State` for `State` in `m`.
+  enums: HashSet<ResourceName>,
+
+  errors: Vec<MglError>,
+}
+
+fn collect_constants(tree: &AstFileTree, declarations: &mut Declarations) {
+  match tree {
+    AstFileTree::Root(box subtree) => collect_constants(subtree, declarations),
+
+    AstFileTree::Node(_, children) => {
+      for child in children {
+        collect_constants(child, declarations);
+      }
+    }
+
+    AstFileTree::Leaf(_, top) => {
+      let module = file_module(top);
+      let constants = &mut declarations.constants;
+      let mut declare = |name: &str, value: IExpr, module: &Module| {
+        constants.insert(full_name_for("const", name, module), (module.clone(), value));
+      };
+
+      for declaration in &top.declarations {
+        match declaration {
+          Declaration::Constant(constant) => declare(&constant.name, constant.value.clone(), &module),
+
+          Declaration::Resource(resource) => {
+            let sub_module = module_add(&module, resource.name.clone());
+
+            for constant in &resource.constants {
+              declare(&constant.name, constant.value.clone(), &sub_module);
+            }
+          }
+
+          Declaration::Enum(enumeration) => {
+            let enum_module = module_add(&module, enumeration.name.clone());
+            let mut names   = HashSet::new();
+            let mut previous = None;
+
+            for variant in &enumeration.variants {
+              let name = &variant.name.content;
+
+              if names.insert(name.clone()) {
+                declare(name, variant.value_after(previous), &enum_module);
+                previous = Some(variant);
+
+              } else {
+                declarations.errors.push(MglError::Constant {
+                  kind: ConstantErrorKind::DuplicateVariant(name.to_string()),
+                  expression: AstDebugInfo::with_location_of(Expression::name(name), &variant.name),
+                });
+              }
+            }
+            declarations.enums.insert(full_name_for("const", &enumeration.name, &module));
+          }
+
+          _ => {}
+        }
+      }
+    }
+  }
+}
+
+/// Reports the variants used in functions and methods that their enumeration doesn't have.
+fn check_variants(tree:      &AstFileTree,
+                  constants: &Declared,
+                  enums:     &HashSet<ResourceName>,
+                  errors:    &mut Vec<MglError>) {
+  match tree {
+    AstFileTree::Root(box subtree) => check_variants(subtree, constants, enums, errors),
+
+    AstFileTree::Node(_, children) => {
+      for child in children {
+        check_variants(child, constants, enums, errors);
+      }
+    }
+
+    AstFileTree::Leaf(_, top) => {
+      let module = file_module(top);
+      let mut check = |statement: &IStat, module: &Module| {
+        for expression in statement.content.expressions() {
+          check_expression_variants(expression, module, constants, enums, errors);
+        }
+      };
+
+      for declaration in &top.declarations {
+        match declaration {
+          Declaration::Function(function) => check(&function.body, &module),
+
+          Declaration::Resource(resource) => {
+            let sub_module = module_add(&module, resource.name.clone());
+
+            for method in &resource.methods {
+              check(&method.body, &sub_module);
+            }
+          }
+
+          _ => {}
+        }
+      }
+    }
+  }
+}
+
+fn check_expression_variants(expression: &IExpr,
+                             module:     &Module,
+                             constants:  &Declared,
+                             enums:      &HashSet<ResourceName>,
+                             errors:     &mut Vec<MglError>) {
+  if let Some(error) = unknown_variant(expression, module, constants, enums) {
+    errors.push(error);
+  }
+
+  for subexpression in expression.content.subexpressions() {
+    check_expression_variants(subexpression, module, constants, enums, errors);
+  }
+}
+
+/// `State::Run` when `State` is an enumeration without a `Run` variant.
+fn unknown_variant(expression: &IExpr,
+                   module:     &Module,
+                   constants:  &Declared,
+                   enums:      &HashSet<ResourceName>) -> Option<MglError> {
+  let names = match expression.as_ref() {
+    Expression::Resource(name) => name.names(),
+    _ => return None
+  };
+
+  let (variant, enumeration) = names.split_last().unwrap();
+
+  if find_constant(expression.as_ref(), module, constants).is_some() ||
+     !candidates(enumeration, module).iter().any(|name| enums.contains(name)) {
+    return None
+  }
+
+  Some(MglError::Constant {
+    kind: ConstantErrorKind::UnknownVariant(enumeration.last().unwrap().clone(), variant.clone()),
+    expression: expression.clone(),
+  })
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+  Num(f64),
+  Str(String),
+  Bool(bool),
+}
+
+impl Value {
+  fn is_integer(&self) -> bool {
+    if let Value::Num(n) = self { n.fract() == 0.0 } else { false }
+  }
+
+  fn expression(self) -> IExpr {
+    IExpr::new(
+      match self {
+        Value::Num(n) if n < 0.0 => Expression::parentheses(IExpr::new(Expression::num(&show_number(n)))),
+        Value::Num(n)  => Expression::num(&show_number(n)),
+        Value::Str(s)  => Expression::Str(s),
+        Value::Bool(b) => Expression::boolean(b),
+      }
+    )
+  }
+}
+
+fn show_number(n: f64) -> String {
+  if n.fract() == 0.0 && n.abs() < 1e15 {
+    format!("{}", n as i64)
+  } else {
+    format!("{}", n)
+  }
+}
+
+struct Evaluator<'a> {
+  declared: &'a Declared,
+  enums: &'a HashSet<ResourceName>,
+
+  /// `None` for the constants that couldn't be computed, which were already reported.
+  values: HashMap<ResourceName, Option<Value>>,
+
+  /// The constants being computed, each depending on the next.
+  path: Vec<ResourceName>,
+
+  errors: Vec<MglError>,
+}
+
+type Evaluation = std::result::Result<Value, Option<MglError>>;
+
+impl<'a> Evaluator<'a> {
+  fn constant(&mut self, name: &ResourceName) -> Option<Value> {
+    if let Some(value) = self.values.get(name) {
+      return value.clone()
+    }
+
+    let declared = self.declared;
+    let (module, expression) = &declared[name];
+    self.path.push(name.clone());
+
+    let value = match self.evaluate(expression, module) {
+      // GML only has integer enumerations
+      Ok(value) if self.is_variant(name) && !value.is_integer() => {
+        self.errors.push(MglError::Constant { kind: ConstantErrorKind::NotInteger, expression: expression.clone() });
+        None
+      }
+
+      Ok(value) => Some(value),
+      Err(error) => {
+        self.errors.extend(error);
+        None
+      }
+    };
+
+    self.path.pop();
+    self.values.insert(name.clone(), value.clone());
+    value
+  }
+
+  fn is_variant(&self, name: &ResourceName) -> bool {
+    let names = name.names();
+    let enumeration = names[..names.len() - 1].iter().map(String::as_str).collect::<Vec<_>>();
+    self.enums.contains(&ResourceName::new(&enumeration))
+  }
+
+  fn evaluate(&mut self, expression: &IExpr, module: &Module) -> Evaluation {
+    use crate::ast::BinaryOp::Dot;
+    use crate::ast::UnaryOp::{Neg, Not};
+    use Expression::*;
+
+    let error = |kind| Err(Some(MglError::Constant { kind, expression: expression.clone() }));
+
+    match expression.as_ref() {
+      Num(n)  => n.parse().map(Value::Num).or_else(|_| error(ConstantErrorKind::NotConstant)),
+      Str(s)  => Ok(Value::Str(s.clone())),
+      Bool(b) => Ok(Value::Bool(*b)),
+
+      Name(_) | Resource(_) => {
+        let name = match find_constant(expression.as_ref(), module, self.declared) {
+          Some(name) => name,
+          None => {
+            return match unknown_variant(expression, module, self.declared, self.enums) {
+              Some(unknown) => Err(Some(unknown)),
+              None => error(ConstantErrorKind::NotConstant),
+            }
+          }
+        };
+
+        if let Some(start) = self.path.iter().position(|n| n == &name) {
+          let mut cycle = self.path[start..].iter().map(|n| n.last().clone()).collect::<Vec<_>>();
+          cycle.push(name.last().clone());
+          return error(ConstantErrorKind::Cycle(cycle))
+        }
+
+        // a constant that failed was already reported, and so are the ones using it
+        self.constant(&name).ok_or(None)
+      }
+
+      Parentheses(e) => self.evaluate(e, module),
+
+      UnaryOp(op, e) => {
+        match (*op, self.evaluate(e, module)?) {
+          (Neg, Value::Num(n))  => Ok(Value::Num(-n)),
+          (Not, Value::Bool(b)) => Ok(Value::Bool(!b)),
+          _ => error(ConstantErrorKind::WrongTypes)
+        }
+      }
+
+      BinaryOp(Dot, _, _) => error(ConstantErrorKind::NotConstant),
+
+      BinaryOp(op, a, b) => {
+        let a = self.evaluate(a, module)?;
+        let b = self.evaluate(b, module)?;
+
+        match binary_op(*op, a, b) {
+          Some(Ok(value)) => Ok(value),
+          Some(Err(())) => error(ConstantErrorKind::DivisionByZero),
+          None => error(ConstantErrorKind::WrongTypes),
+        }
+      }
+
+      TernaryOp(condition, a, b) => {
+        match self.evaluate(condition, module)? {
+          Value::Bool(true)  => self.evaluate(a, module),
+          Value::Bool(false) => self.evaluate(b, module),
+          _ => error(ConstantErrorKind::WrongTypes)
+        }
+      }
+
+      Call(..) | Indexing(..) | Array(_) | Map(_) => error(ConstantErrorKind::NotConstant),
+    }
+  }
+}
+
+/// `None` if the operator can't be used with these values, `Err` for a division by zero.
+fn binary_op(op: BinaryOp, a: Value, b: Value) -> Option<std::result::Result<Value, ()>> {
+  use BinaryOp::*;
+  use Value::*;
+
+  let integer = |n: f64| n as i64;
+
+  let value = match (op, a, b) {
+    (Add, Str(a), Str(b)) => Str(a + &b),
+    (Add, Num(a), Num(b)) => Num(a + b),
+    (Sub, Num(a), Num(b)) => Num(a - b),
+    (Mul, Num(a), Num(b)) => Num(a * b),
+
+    (Div, Num(_), Num(b)) | (Mod, Num(_), Num(b)) | (IntDiv, Num(_), Num(b)) if b == 0.0 => {
+      return Some(Err(()))
+    }
+
+    (Div,    Num(a), Num(b)) => Num(a / b),
+    (Mod,    Num(a), Num(b)) => Num(a % b),
+    (IntDiv, Num(a), Num(b)) => Num((a / b).trunc()),
+
+    (BitAnd, Num(a), Num(b)) => Num((integer(a) & integer(b)) as f64),
+    (BitOr,  Num(a), Num(b)) => Num((integer(a) | integer(b)) as f64),
+    (BitXor, Num(a), Num(b)) => Num((integer(a) ^ integer(b)) as f64),
+    (Shl,    Num(a), Num(b)) => Num(integer(a).checked_shl(integer(b) as u32)? as f64),
+    (Shr,    Num(a), Num(b)) => Num(integer(a).checked_shr(integer(b) as u32)? as f64),
+
+    (Lt,  Num(a), Num(b)) => Bool(a < b),
+    (Gt,  Num(a), Num(b)) => Bool(a > b),
+    (Leq, Num(a), Num(b)) => Bool(a <= b),
+    (Geq, Num(a), Num(b)) => Bool(a >= b),
+
+    (Eq,   a, b) if same_type(&a, &b) => Bool(a == b),
+    (Diff, a, b) if same_type(&a, &b) => Bool(a != b),
+
+    (And, Bool(a), Bool(b)) => Bool(a && b),
+    (Or,  Bool(a), Bool(b)) => Bool(a || b),
+    (Xor, Bool(a), Bool(b)) => Bool(a != b),
+
+    _ => return None
+  };
+  Some(Ok(value))
+}
+
+fn same_type(a: &Value, b: &Value) -> bool {
+  std::mem::discriminant(a) == std::mem::discriminant(b)
+}
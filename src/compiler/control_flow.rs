@@ -0,0 +1,104 @@
+//! Checks that `break` and `continue` have something to leave. As in GML,
+//! `with` loops over instances. Cases never fall through, so a `break` directly
+//! inside one would only leave the `switch` and is rejected.
+
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::file_reader::*;
+
+#[derive(Clone, Copy)]
+struct Scope {
+  can_break: bool,
+  can_continue: bool,
+  in_switch: bool,
+}
+
+const OUTSIDE: Scope = Scope { can_break: false, can_continue: false, in_switch: false };
+const LOOP: Scope    = Scope { can_break: true,  can_continue: true,  in_switch: false };
+
+pub fn check_control_flow(tree: &AstFileTree) -> TopResult<()> {
+  let mut errors = Vec::new();
+  check_tree(tree, &mut errors);
+
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(errors)
+  }
+}
+
+fn check_tree(tree: &AstFileTree, errors: &mut Vec<MglError>) {
+  match tree {
+    AstFileTree::Root(box subtree) => check_tree(subtree, errors),
+
+    AstFileTree::Node(_, children) => {
+      for child in children {
+        check_tree(child, errors);
+      }
+    }
+
+    AstFileTree::Leaf(_, top) => {
+      for declaration in &top.declarations {
+        match declaration {
+          Declaration::Function(function) => check_statement(&function.body, OUTSIDE, errors),
+
+          Declaration::Resource(resource) => {
+            for method in &resource.methods {
+              check_statement(&method.body, OUTSIDE, errors);
+            }
+          }
+
+          _ => {}
+        }
+      }
+    }
+  }
+}
+
+fn check_statement(statement: &IStat, scope: Scope, errors: &mut Vec<MglError>) {
+  match statement.as_ref() {
+    Statement::Break if scope.in_switch => {
+      errors.push(MglError::BreakInSwitch { statement: statement.clone() });
+    }
+
+    Statement::Break if !scope.can_break => {
+      errors.push(MglError::OutsideLoop { statement: statement.clone() });
+    }
+
+    Statement::Continue if !scope.can_continue => {
+      errors.push(MglError::OutsideLoop { statement: statement.clone() });
+    }
+
+    Statement::Body(statements) => {
+      for statement in statements {
+        check_statement(statement, scope, errors);
+      }
+    }
+
+    Statement::If(_, then, or_else) => {
+      check_statement(then, scope, errors);
+      if let Some(or_else) = or_else {
+        check_statement(or_else, scope, errors);
+      }
+    }
+
+    Statement::Switch(_, cases, default) => {
+      let scope = Scope { in_switch: true, ..scope };
+
+      for case in cases {
+        check_statement(&case.body, scope, errors);
+      }
+      if let Some(default) = default {
+        check_statement(default, scope, errors);
+      }
+    }
+
+    Statement::With(_, body)    |
+    Statement::While(_, body)   |
+    Statement::Repeat(_, body)  |
+    Statement::DoUntil(body, _) |
+    Statement::For(_, _, body)  => check_statement(body, LOOP, errors),
+
+    _ => {}
+  }
+}
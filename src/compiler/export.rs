@@ -0,0 +1,174 @@
+//! Shared pieces of the exporters that turn a `Project` into Game Maker project files.
+
+use std::fs;
+use std::str::FromStr;
+use std::path::{Path, PathBuf};
+
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::script::build_resource_name;
+use crate::compiler::gms1::export_gms1;
+use crate::compiler::gms2::export_gms2;
+use crate::resources::project::Project;
+use crate::resources::room::Room;
+use crate::manifest::ProjectManifest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+  Gms1,
+  Gms2,
+}
+
+pub const TARGET_NAMES: &[&str] = &["gms1", "gms2"];
+
+impl FromStr for Target {
+  type Err = MglError;
+
+  fn from_str(s: &str) -> Result<Target> {
+    match s {
+      "gms1" => Ok(Target::Gms1),
+      "gms2" => Ok(Target::Gms2),
+      _ => MglError::unknown_option(s, TARGET_NAMES)
+    }
+  }
+}
+
+pub struct ExportContext {
+  pub name: String,
+  pub assets: Vec<PathBuf>,
+  pub output: PathBuf,
+}
+
+impl ExportContext {
+  pub fn new(name: &str, source: &Path, output: &Path) -> Self {
+    ExportContext {
+      name: String::from(name),
+      assets: vec![source.to_path_buf()],
+      output: output.to_path_buf(),
+    }
+  }
+
+  pub fn with_assets(self, assets: &[PathBuf]) -> Self {
+    ExportContext {
+      name: self.name,
+      assets: Vec::from(assets),
+      output: self.output,
+    }
+  }
+
+  pub fn output_path(&self, parts: &[&str]) -> PathBuf {
+    let mut path = self.output.clone();
+    for part in parts {
+      path.push(part);
+    }
+    path
+  }
+
+  /// Relative asset paths are looked up in each asset root, in order.
+  pub fn source_path(&self, file: &Path) -> PathBuf {
+    if file.is_absolute() {
+      return file.to_path_buf()
+    }
+
+    for root in &self.assets {
+      let path = root.join(file);
+      if path.exists() {
+        return path
+      }
+    }
+    self.assets.first().map(|root| root.join(file)).unwrap_or(file.to_path_buf())
+  }
+}
+
+
+/// Exports the project as described by its manifest. The output directory
+/// and target given in the command line win over the manifest's.
+pub fn export_project(project:  &Project,
+                      manifest: &ProjectManifest,
+                      output:   Option<PathBuf>,
+                      target:   Option<Target>) -> Result<PathBuf> {
+
+  let mut project = project.clone();
+  let output = output.unwrap_or(manifest.output.clone());
+  let target = target.unwrap_or(manifest.target);
+
+  if let Some(room) = &manifest.default_room {
+    if !project.set_first_room(room) {
+      return Err(manifest.error("default_room", ManifestErrorKind::UnknownRoom(room.to_string())))
+    }
+  }
+
+  let context = ExportContext::new(&manifest.name, &manifest.directory, &output)
+                  .with_assets(&manifest.assets);
+
+  match target {
+    Target::Gms1 => export_gms1(&project, &context),
+    Target::Gms2 => export_gms2(&project, &context),
+  }
+}
+
+
+/// The name a resource gets inside Game Maker. Scripts drop their `script`
+/// module so that functions can be called by the name they were declared with,
+/// every other resource keeps its kind (`sprite::s` -> `sprite__s`) so that
+/// resources of different kinds sharing a name don't collide.
+pub fn asset_name(name: &ResourceName) -> String {
+  match name {
+    ResourceName::InModule(module, box rest) if module == "script" => {
+      build_resource_name(rest)
+    }
+    _ => build_resource_name(name)
+  }
+}
+
+pub fn asset_name_in(kind: &str, name: &ResourceName) -> String {
+  asset_name(&name.qualified(kind))
+}
+
+/// Room files have no view angle, so the creation code of a room sets the
+/// angles, written by `set_angle`, after calling the room's own creation code.
+pub fn room_creation_code(room: &Room, set_angle: impl Fn(usize, f64) -> String) -> String {
+  let call = room.creation_code.as_ref().map(|c| format!("{}();\n", asset_name_in("script", c)));
+
+  let angles = room.views.iter().enumerate().filter(|(_, view)| view.angle != 0.0).map(|(index, view)| {
+    set_angle(index, view.angle)
+  });
+  call.into_iter().chain(angles).collect()
+}
+
+
+pub fn write_file(path: &Path, contents: &str) -> Result<()> {
+  if let Some(directory) = path.parent() {
+    create_directory(directory)?;
+  }
+
+  match fs::write(path, contents) {
+    Ok(()) => Ok(()),
+    Err(e) => MglError::io(path, e)
+  }
+}
+
+pub fn create_directory(path: &Path) -> Result<()> {
+  match fs::create_dir_all(path) {
+    Ok(()) => Ok(()),
+    Err(e) => MglError::io(path, e)
+  }
+}
+
+pub fn copy_file(from: &Path, to: &Path) -> Result<()> {
+  if let Some(directory) = to.parent() {
+    create_directory(directory)?;
+  }
+
+  match fs::copy(from, to) {
+    Ok(_)  => Ok(()),
+    Err(e) => MglError::io(from, e)
+  }
+}
+
+pub fn file_extension(path: &Path) -> String {
+  path.extension()
+      .and_then(|e| e.to_str())
+      .map(|e| format!(".{}", e))
+      .unwrap_or_default()
+}
@@ -6,8 +6,10 @@ use crate::utility::files::{valid_paths, path_string, path_file_name};
 use crate::ast::Top;
 use crate::error::*;
 use crate::parser::parse_code;
+use crate::parser::recovery::parse_code_recovering;
 use crate::parser::context::*;
 use crate::source_files::*;
+use crate::manifest::ProjectManifest;
 
 #[derive(Debug, Clone)]
 pub enum AstFileTree {
@@ -16,16 +18,20 @@ pub enum AstFileTree {
   Leaf(String, Top)
 }
 
-pub fn read_file_as_ast(path: &PathBuf) -> Result<Top> {
+/// Something read along with the syntax errors found in it, the declarations
+/// around each error are still there so that later passes can check them too.
+pub type Recovered<T> = (T, Vec<MglError>);
+
+pub fn read_file_as_ast(path: &PathBuf) -> Recovered<Top> {
   match read_to_string(path) {
     Ok(mgl) => {
       let source = SourceFile::new(path.clone());
-      parse_code(ParserContext::new(&*mgl).with_file(source))
+      parse_code_recovering(ParserContext::new(&*mgl).with_file(source))
     }
 
     Err(e) => {
       eprintln!("An error has occured while trying to read '{}': {}\n", path_string(&path), e);
-      parse_code("")
+      (parse_code("").unwrap(), Vec::new())
     }
   }
 }
@@ -41,51 +47,64 @@ impl AstFileTree {
     )
   }
 
-  pub fn from_project(project_file: Option<PathBuf>) -> TopResult<AstFileTree> {
+  pub fn from_project(project_file: Option<PathBuf>) -> TopResult<Recovered<AstFileTree>> {
     if let Some(project) = project_file {
-      let mut source_directory = project.parent().unwrap().to_path_buf();
-      source_directory.push("src");
-      Ok(AstFileTree::Root(box AstFileTree::from_directory(&source_directory)?))
+      ProjectManifest::from_file(&project).map(|manifest| AstFileTree::from_manifest(&manifest))
 
     } else {
-      Ok(AstFileTree::new_root())
+      Ok((AstFileTree::new_root(), Vec::new()))
+    }
+  }
+
+  /// The contents of every source directory are merged at the top of the tree.
+  pub fn from_manifest(manifest: &ProjectManifest) -> Recovered<AstFileTree> {
+    let mut files  = Vec::new();
+    let mut errors = Vec::new();
+
+    for source_directory in &manifest.sources {
+      match AstFileTree::from_directory(source_directory) {
+        (AstFileTree::Node(_, mut children), mut e) => {
+          files.append(&mut children);
+          errors.append(&mut e);
+        }
+        _ => unreachable!(),
+      }
     }
+
+    (AstFileTree::Root(box AstFileTree::Node(String::new(), files)), errors)
   }
 
 
-  pub fn from_directory(directory: &PathBuf) -> TopResult<AstFileTree>  {
+  pub fn from_directory(directory: &PathBuf) -> Recovered<AstFileTree>  {
     let mut asts = Vec::new();
     let mut errors = Vec::new();
     let directory_name = path_file_name(directory);
 
     if let Ok(entries) = read_dir(directory) {
-      for file in valid_paths(entries) {
+      let mut files = valid_paths(entries).collect::<Vec<_>>();
+      files.sort();
+
+      for file in files {
         if is_mgl_file(&file) {
-          match AstFileTree::from_file(&file) {
-            Ok(ast) => asts.push(ast),
-            Err(e)  => errors.push(e),
-          }
+          let (ast, mut e) = AstFileTree::from_file(&file);
+          asts.push(ast);
+          errors.append(&mut e);
 
         } else if file.is_dir() {
-          match AstFileTree::from_directory(&file) {
-            Ok(ast)    => asts.push(ast),
-            Err(mut e) => errors.append(&mut e),
-          }
+          let (ast, mut e) = AstFileTree::from_directory(&file);
+          asts.push(ast);
+          errors.append(&mut e);
         }
       }
     }
-    if errors.is_empty() {
-      Ok(AstFileTree::Node(directory_name, asts))
-    } else {
-      Err(errors)
-    }
+    (AstFileTree::Node(directory_name, asts), errors)
   }
 
 
-  pub fn from_file(path: &PathBuf) -> Result<AstFileTree> {
+  pub fn from_file(path: &PathBuf) -> Recovered<AstFileTree> {
     let name = path_file_name(path);
-    let ast = read_file_as_ast(path)?;
-    Ok(AstFileTree::Leaf(name, ast))
+    let (ast, errors) = read_file_as_ast(path);
+    (AstFileTree::Leaf(name, ast), errors)
   }
 
   pub fn is_root(&self) -> bool {
@@ -0,0 +1,620 @@
+//! Writes a `Project` as a Game Maker: Studio 1.4 project, that is, a `<name>.gmx`
+//! directory holding the `<name>.project.gmx` index and one file per resource.
+
+use std::path::PathBuf;
+
+use crate::ast::*;
+use crate::error::*;
+use crate::event::Event;
+use crate::compiler::xml::*;
+use crate::compiler::export::*;
+use crate::compiler::script::build_script;
+use crate::resources::project::{Project, Item, Items};
+use crate::resources::script::Script;
+use crate::resources::object::{Object, PhysicsShape};
+use crate::resources::sprite::Sprite;
+use crate::resources::sound::Sound;
+use crate::resources::font::Font;
+use crate::resources::path::Path;
+use crate::resources::background::Background;
+use crate::resources::room::{Room, RoomBackground, Tile, View, InstanceItem, ROOM_BACKGROUNDS, ROOM_VIEWS};
+use crate::resources::instance::Instance;
+
+const GMX_COMMENT: &str =
+  "This Document is generated by GameMaker, if you edit it by hand then you do so at your own risk!";
+
+const FIRST_INSTANCE_ID: u32 = 100000;
+const FIRST_TILE_ID:     u32 = 10000000;
+
+pub fn export_gms1(project: &Project, context: &ExportContext) -> Result<PathBuf> {
+  let directory = context.output_path(&[&format!("{}.gmx", context.name)]);
+  let mut exporter = Gms1Exporter {
+    context,
+    directory: directory.clone(),
+    next_instance_id: FIRST_INSTANCE_ID,
+    next_tile_id: FIRST_TILE_ID,
+  };
+
+  exporter.export(project)?;
+  Ok(directory)
+}
+
+struct Gms1Exporter<'a> {
+  context: &'a ExportContext,
+  directory: PathBuf,
+  next_instance_id: u32,
+  next_tile_id: u32,
+}
+
+impl<'a> Gms1Exporter<'a> {
+  fn export(&mut self, project: &Project) -> Result<()> {
+    let mut index = XmlBuilder::document(GMX_COMMENT);
+    index.open("assets");
+
+    index.open_with("Configs", &[("name", String::from("configs"))]);
+    index.leaf("Config", "Configs\\Default");
+    index.close("Configs");
+    self.write_config()?;
+
+    index.empty("NewExtensions");
+
+    self.export_items(&mut index, ("sounds", "sound", "sound"), &project.sounds, |e, n, s| {
+      e.export_sound(n, s)
+    })?;
+
+    self.export_items(&mut index, ("sprites", "sprite", "sprites"), &project.sprites, |e, n, s| {
+      e.export_sprite(n, s)
+    })?;
+
+    self.export_items(&mut index, ("backgrounds", "background", "background"), &project.backgrounds, |e, n, b| {
+      e.export_background(n, b)
+    })?;
+
+    self.export_items(&mut index, ("paths", "path", "paths"), &project.paths, |e, n, p| {
+      e.export_path(n, p)
+    })?;
+
+    self.export_items(&mut index, ("scripts", "script", "scripts"), &project.scripts, |e, n, s| {
+      e.export_script(n, s)
+    })?;
+
+    self.export_items(&mut index, ("fonts", "font", "fonts"), &project.fonts, |e, n, f| {
+      e.export_font(n, f)
+    })?;
+
+    self.export_items(&mut index, ("objects", "object", "objects"), &project.objects, |e, n, o| {
+      e.export_object(n, o)
+    })?;
+
+    self.export_items(&mut index, ("rooms", "room", "rooms"), &project.rooms, |e, n, r| {
+      e.export_room(n, r)
+    })?;
+
+    index.empty("help");
+    index.close("assets");
+
+    let file_name = format!("{}.project.gmx", self.context.name);
+    write_file(&self.directory.join(file_name), &index.finish())
+  }
+
+  /// `names` holds the tag of the group, the tag of each entry and the directory
+  /// the files go in, which for historical reasons isn't always the plural.
+  fn export_items<T, F>(&mut self,
+                        index:  &mut XmlBuilder,
+                        names:  (&str, &str, &str),
+                        items:  &Items<T>,
+                        export: F) -> Result<()>
+    where F: Fn(&mut Self, &ResourceName, &T) -> Result<String> + Copy {
+
+    let (group_tag, _, top_name) = names;
+
+    if items.is_empty() {
+      return Ok(())
+    }
+
+    index.open_with(group_tag, &[("name", String::from(top_name))]);
+    self.export_item_list(index, names, items, export)?;
+    index.close(group_tag);
+    Ok(())
+  }
+
+  fn export_item_list<T, F>(&mut self,
+                            index:  &mut XmlBuilder,
+                            names:  (&str, &str, &str),
+                            items:  &Items<T>,
+                            export: F) -> Result<()>
+    where F: Fn(&mut Self, &ResourceName, &T) -> Result<String> + Copy {
+
+    let (group_tag, item_tag, _) = names;
+
+    for item in items {
+      match item {
+        Item::Group(name, sub_items) => {
+          index.open_with(group_tag, &[("name", name.clone())]);
+          self.export_item_list(index, names, sub_items, export)?;
+          index.close(group_tag);
+        }
+
+        Item::File(name, resource) => {
+          let entry = export(self, name, resource)?;
+          index.leaf(item_tag, entry);
+        }
+      }
+    }
+    Ok(())
+  }
+
+
+  fn write_config(&self) -> Result<()> {
+    let mut config = XmlBuilder::document(GMX_COMMENT);
+    config.open("Config");
+    config.open("Options");
+    config.leaf("option_game_speed", 30);
+    config.leaf("option_gameid", 0);
+    config.leaf("option_author", "");
+    config.leaf("option_version", "1.0.0.0");
+    config.leaf("option_windows_display_name", &self.context.name);
+    config.close("Options");
+    config.close("Config");
+
+    write_file(&self.directory.join("Configs").join("Default.config.gmx"), &config.finish())
+  }
+
+
+  fn export_script(&mut self, name: &ResourceName, script: &Script) -> Result<String> {
+    let name = asset_name(name);
+    let file = format!("{}.gml", name);
+
+    write_file(&self.directory.join("scripts").join(&file), &build_script(script.clone())?)?;
+    Ok(format!("scripts\\{}", file))
+  }
+
+
+  fn export_object(&mut self, name: &ResourceName, object: &Object) -> Result<String> {
+    let name = asset_name(name);
+    let sprite  = object.sprite.as_ref().map(|s| asset_name_in("sprite", s));
+    let mask    = object.mask.as_ref().map(|s| asset_name_in("sprite", s));
+    let parent  = object.parent.as_ref().map(|p| asset_name_in("object", &p.content));
+    let physics = &object.physics;
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("object");
+    xml.leaf("spriteName", sprite.unwrap_or(String::from("<undefined>")));
+    xml.leaf("solid", gmx_bool(object.solid));
+    xml.leaf("visible", gmx_bool(object.visible));
+    xml.leaf("depth", object.depth);
+    xml.leaf("persistent", gmx_bool(object.persistent));
+    xml.leaf("parentName", parent.unwrap_or(String::from("<undefined>")));
+    xml.leaf("maskName", mask.unwrap_or(String::from("<undefined>")));
+
+    xml.open("events");
+    for (event, script) in &object.events {
+      self.export_event(&mut xml, event, script);
+    }
+    xml.close("events");
+
+    xml.leaf("PhysicsObject", gmx_bool(physics.enabled));
+    xml.leaf("PhysicsObjectSensor", gmx_bool(physics.sensor));
+    xml.leaf("PhysicsObjectShape", physics.shape.unwrap_or(PhysicsShape::Circle) as u32);
+    xml.leaf("PhysicsObjectDensity", gmx_real(physics.density));
+    xml.leaf("PhysicsObjectRestitution", gmx_real(physics.restitution));
+    xml.leaf("PhysicsObjectGroup", physics.group);
+    xml.leaf("PhysicsObjectLinearDamping", gmx_real(physics.linear_damping));
+    xml.leaf("PhysicsObjectAngularDamping", gmx_real(physics.angular_damping));
+    xml.leaf("PhysicsObjectFriction", gmx_real(physics.friction));
+    xml.leaf("PhysicsObjectAwake", gmx_bool(physics.awake));
+    xml.leaf("PhysicsObjectKinematic", gmx_bool(physics.kinematic));
+
+    if physics.shape_points.is_empty() {
+      xml.empty("PhysicsShapePoints");
+    } else {
+      xml.open("PhysicsShapePoints");
+      for point in &physics.shape_points {
+        xml.leaf("point", format!("{},{}", point.x, point.y));
+      }
+      xml.close("PhysicsShapePoints");
+    }
+    xml.close("object");
+
+    write_file(&self.directory.join("objects").join(format!("{}.object.gmx", name)), &xml.finish())?;
+    Ok(format!("objects\\{}", name))
+  }
+
+  /// Every event becomes a single "Execute Code" action calling the event's script.
+  fn export_event(&self, xml: &mut XmlBuilder, event: &Event, script: &ResourceName) {
+    let event_type = ("eventtype", event.event_type().to_string());
+
+    if let Event::Collision(object) = event {
+      xml.open_with("event", &[event_type, ("ename", asset_name_in("object", object))]);
+    } else {
+      xml.open_with("event", &[event_type, ("enumb", event.event_number().to_string())]);
+    }
+
+    xml.open("action");
+    xml.leaf("libid", 1);
+    xml.leaf("id", 603);
+    xml.leaf("kind", 7);
+    xml.leaf("userelative", 0);
+    xml.leaf("isquestion", 0);
+    xml.leaf("useapplyto", gmx_bool(true));
+    xml.leaf("exetype", 2);
+    xml.leaf("functionname", "");
+    xml.leaf("codestring", "");
+    xml.leaf("whoName", "self");
+    xml.leaf("relative", 0);
+    xml.leaf("isnot", 0);
+    xml.open("arguments");
+    xml.open("argument");
+    xml.leaf("kind", 1);
+    xml.leaf("string", format!("{}();", asset_name_in("script", script)));
+    xml.close("argument");
+    xml.close("arguments");
+    xml.close("action");
+    xml.close("event");
+  }
+
+
+  fn export_sprite(&mut self, name: &ResourceName, sprite: &Sprite) -> Result<String> {
+    let name = asset_name(name);
+    let directory = self.directory.join("sprites");
+    let (x, y) = sprite.origin_point();
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("sprite");
+    xml.leaf("type", 0);
+    xml.leaf("xorig", x);
+    xml.leaf("yorigin", y);
+    xml.leaf("colkind", sprite.collision_kind as i32);
+    xml.leaf("coltolerance", sprite.collision_tolerance);
+    xml.leaf("sepmasks", gmx_bool(sprite.separate_masks));
+    xml.leaf("bboxmode", sprite.bounding_box.mode as i32);
+    xml.leaf("bbox_left", sprite.bounding_box.left);
+    xml.leaf("bbox_right", sprite.bounding_box.right);
+    xml.leaf("bbox_top", sprite.bounding_box.top);
+    xml.leaf("bbox_bottom", sprite.bounding_box.bottom);
+    xml.leaf("HTile", gmx_bool(sprite.texture.horizontal));
+    xml.leaf("VTile", gmx_bool(sprite.texture.vertical));
+    xml.open("TextureGroups");
+    xml.leaf("TextureGroup0", sprite.texture.texture_group);
+    xml.close("TextureGroups");
+    xml.leaf("For3D", gmx_bool(sprite.texture.used_for_3d));
+    xml.leaf("width", sprite.width);
+    xml.leaf("height", sprite.height);
+
+    xml.open("frames");
+    for (index, frame) in sprite.frames.iter().enumerate() {
+      let image = format!("{}_{}.png", name, index);
+
+      if let Some(data) = &frame.data {
+        copy_file(&self.context.source_path(data), &directory.join("images").join(&image))?;
+      }
+      xml.leaf_with("frame", &[("index", index.to_string())], format!("images\\{}", image));
+    }
+    xml.close("frames");
+    xml.close("sprite");
+
+    write_file(&directory.join(format!("{}.sprite.gmx", name)), &xml.finish())?;
+    Ok(format!("sprites\\{}", name))
+  }
+
+
+  fn export_sound(&mut self, name: &ResourceName, sound: &Sound) -> Result<String> {
+    let name = asset_name(name);
+    let directory = self.directory.join("sound");
+    let extension = file_extension(&sound.data);
+    let data = format!("{}{}", name, extension);
+
+    if !sound.data.as_os_str().is_empty() {
+      copy_file(&self.context.source_path(&sound.data), &directory.join("audio").join(&data))?;
+    }
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("sound");
+    xml.leaf("kind", sound.kind.clone() as i32);
+    xml.leaf("extension", &extension);
+    xml.leaf("origname", format!("sound\\audio\\{}", data));
+    xml.leaf("effects", 0);
+    xml.open("volume");
+    xml.leaf("volume", sound.volume);
+    xml.close("volume");
+    xml.leaf("pan", sound.pan);
+    xml.open("bitRates");
+    xml.leaf("bitRate", sound.bit_rate);
+    xml.close("bitRates");
+    xml.open("sampleRates");
+    xml.leaf("sampleRate", sound.sample_rate);
+    xml.close("sampleRates");
+    xml.open("types");
+    xml.leaf("type", 1);
+    xml.close("types");
+    xml.open("bitDepths");
+    xml.leaf("bitDepth", sound.bit_depth);
+    xml.close("bitDepths");
+    xml.leaf("preload", gmx_bool(sound.preload));
+    xml.leaf("data", &data);
+    xml.leaf("compressed", gmx_bool(sound.compress));
+    xml.leaf("streamed", 0);
+    xml.leaf("uncompressOnLoad", gmx_bool(sound.uncompress_on_load));
+    xml.leaf("audioGroup", sound.audio_group);
+    xml.close("sound");
+
+    write_file(&directory.join(format!("{}.sound.gmx", name)), &xml.finish())?;
+    Ok(format!("sound\\{}", name))
+  }
+
+
+  fn export_background(&mut self, name: &ResourceName, background: &Background) -> Result<String> {
+    let name = asset_name(name);
+    let directory = self.directory.join("background");
+    let image = format!("{}.png", name);
+
+    if let Some(data) = &background.image {
+      copy_file(&self.context.source_path(data), &directory.join("images").join(&image))?;
+    }
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("background");
+    xml.leaf("istileset", gmx_bool(background.use_as_tileset));
+    xml.leaf("tilewidth", background.tile_width);
+    xml.leaf("tileheight", background.tile_height);
+    xml.leaf("tilexoff", background.horizontal_offset);
+    xml.leaf("tileyoff", background.vertical_offset);
+    xml.leaf("tilehsep", background.horizontal_separation);
+    xml.leaf("tilevsep", background.vertical_separation);
+    xml.leaf("HTile", gmx_bool(background.texture.horizontal));
+    xml.leaf("VTile", gmx_bool(background.texture.vertical));
+    xml.open("TextureGroups");
+    xml.leaf("TextureGroup0", background.texture.texture_group);
+    xml.close("TextureGroups");
+    xml.leaf("For3D", gmx_bool(background.texture.used_for_3d));
+    xml.leaf("width", background.width);
+    xml.leaf("height", background.height);
+    xml.leaf("data", format!("images\\{}", image));
+    xml.close("background");
+
+    write_file(&directory.join(format!("{}.background.gmx", name)), &xml.finish())?;
+    Ok(format!("background\\{}", name))
+  }
+
+
+  fn export_path(&mut self, name: &ResourceName, path: &Path) -> Result<String> {
+    let name = asset_name(name);
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("path");
+    xml.leaf("kind", if path.smooth { 1 } else { 0 });
+    xml.leaf("closed", gmx_bool(path.closed));
+    xml.leaf("precision", path.precision);
+    xml.leaf("backroom", -1);
+    xml.leaf("hsnap", 16);
+    xml.leaf("vsnap", 16);
+    xml.open("points");
+    for point in &path.points {
+      xml.leaf("point", format!("{},{},{}", gmx_real(point.x), gmx_real(point.y), gmx_real(point.speed)));
+    }
+    xml.close("points");
+    xml.close("path");
+
+    write_file(&self.directory.join("paths").join(format!("{}.path.gmx", name)), &xml.finish())?;
+    Ok(format!("paths\\{}", name))
+  }
+
+
+  /// The glyphs and their texture are left for the IDE to generate.
+  fn export_font(&mut self, name: &ResourceName, font: &Font) -> Result<String> {
+    let name = asset_name(name);
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("font");
+    xml.leaf("name", &font.name);
+    xml.leaf("size", font.size);
+    xml.leaf("bold", gmx_bool(font.bold));
+    xml.leaf("renderhq", gmx_bool(true));
+    xml.leaf("italic", gmx_bool(font.italic));
+    xml.leaf("charset", font.charset);
+    xml.leaf("aa", font.antialiasing);
+    xml.leaf("includeTTF", 0);
+    xml.leaf("TTFName", "");
+    xml.open("texgroups");
+    xml.leaf("texgroup0", font.texture_group);
+    xml.close("texgroups");
+    xml.open("ranges");
+    for (index, range) in font.character_ranges().iter().enumerate() {
+      xml.leaf(&format!("range{}", index), format!("{},{}", range.first, range.last));
+    }
+    xml.close("ranges");
+    xml.empty("glyphs");
+    xml.empty("kerningPairs");
+    xml.close("font");
+
+    write_file(&self.directory.join("fonts").join(format!("{}.font.gmx", name)), &xml.finish())?;
+    Ok(format!("fonts\\{}", name))
+  }
+
+
+  fn export_room(&mut self, name: &ResourceName, room: &Room) -> Result<String> {
+    let name = asset_name(name);
+
+    let mut xml = XmlBuilder::document(GMX_COMMENT);
+    xml.open("room");
+    xml.leaf("caption", "");
+    xml.leaf("width", room.width);
+    xml.leaf("height", room.height);
+    xml.leaf("vsnap", 32);
+    xml.leaf("hsnap", 32);
+    xml.leaf("isometric", 0);
+    xml.leaf("speed", room.speed);
+    xml.leaf("persistent", gmx_bool(room.persistent));
+    xml.leaf("colour", gmx_colour(room.color));
+    xml.leaf("showcolour", gmx_bool(true));
+    xml.leaf("code", room_creation_code(room, |index, angle| format!("view_angle[{}] = {};\n", index, angle)));
+    xml.leaf("enableViews", gmx_bool(room.enable_views));
+    xml.leaf("clearViewBackground", gmx_bool(room.clear_view_background));
+    xml.leaf("clearDisplayBuffer", gmx_bool(room.clear_display_buffer));
+
+    xml.open("backgrounds");
+    for index in 0..ROOM_BACKGROUNDS {
+      export_room_background(&mut xml, room.backgrounds.get(index));
+    }
+    xml.close("backgrounds");
+
+    xml.open("views");
+    for index in 0..ROOM_VIEWS {
+      export_view(&mut xml, room.views.get(index));
+    }
+    xml.close("views");
+
+    xml.open("instances");
+    for item in &room.instances {
+      if let InstanceItem::Resolved(instance) = item {
+        self.export_instance(&mut xml, instance);
+      }
+    }
+    xml.close("instances");
+
+    if room.tiles.is_empty() {
+      xml.empty("tiles");
+    } else {
+      xml.open("tiles");
+      for tile in &room.tiles {
+        self.export_tile(&mut xml, tile);
+      }
+      xml.close("tiles");
+    }
+    xml.leaf("PhysicsWorld", 0);
+    xml.leaf("PhysicsWorldTop", 0);
+    xml.leaf("PhysicsWorldLeft", 0);
+    xml.leaf("PhysicsWorldRight", room.width);
+    xml.leaf("PhysicsWorldBottom", room.height);
+    xml.leaf("PhysicsWorldGravityX", 0);
+    xml.leaf("PhysicsWorldGravityY", 10);
+    xml.leaf("PhysicsWorldPixToMeters", "0.100000001490116");
+    xml.close("room");
+
+    write_file(&self.directory.join("rooms").join(format!("{}.room.gmx", name)), &xml.finish())?;
+    Ok(format!("rooms\\{}", name))
+  }
+
+  fn export_tile(&mut self, xml: &mut XmlBuilder, tile: &Tile) {
+    self.next_tile_id += 1;
+    let background = tile.background.as_ref().map(|b| asset_name_in("background", b)).unwrap_or_default();
+
+    xml.empty_with("tile", &[
+      ("bgName", background),
+      ("x",      tile.x.to_string()),
+      ("y",      tile.y.to_string()),
+      ("w",      tile.width.to_string()),
+      ("h",      tile.height.to_string()),
+      ("xo",     tile.left.to_string()),
+      ("yo",     tile.top.to_string()),
+      ("id",     self.next_tile_id.to_string()),
+      ("name",   format!("inst_{:08X}", self.next_tile_id)),
+      ("depth",  tile.depth.to_string()),
+      ("locked", String::from("0")),
+      ("colour", String::from("4294967295")),
+      ("scaleX", String::from("1")),
+      ("scaleY", String::from("1")),
+    ]);
+  }
+
+  fn export_instance(&mut self, xml: &mut XmlBuilder, instance: &Instance) {
+    let id = if instance.id == 0 {
+      self.next_instance_id += 1;
+      self.next_instance_id
+    } else {
+      instance.id
+    };
+
+    let code = instance.creation_code.as_ref()
+                                     .map(|c| format!("{}();", asset_name_in("script", c)))
+                                     .unwrap_or_default();
+
+    let alpha  = (instance.alpha.clamp(0.0, 1.0) * 255.0).round() as u64;
+    let colour = (alpha << 24) | gmx_colour(instance.color) as u64;
+
+    xml.empty_with("instance", &[
+      ("objName",  asset_name_in("object", &instance.object)),
+      ("x",        instance.x.to_string()),
+      ("y",        instance.y.to_string()),
+      ("name",     format!("inst_{:08X}", id)),
+      ("locked",   String::from("0")),
+      ("code",     code),
+      ("scaleX",   instance.scale.to_string()),
+      ("scaleY",   instance.scale.to_string()),
+      ("colour",   colour.to_string()),
+      ("rotation", instance.rotation.to_string()),
+    ]);
+  }
+}
+
+/// Backgrounds past the ones the room has are left empty.
+fn export_room_background(xml: &mut XmlBuilder, background: Option<&RoomBackground>) {
+  let empty = RoomBackground { visible: false, ..RoomBackground::default() };
+  let background = background.unwrap_or(&empty);
+  let name = background.background.as_ref().map(|b| asset_name_in("background", b)).unwrap_or_default();
+
+  xml.empty_with("background", &[
+    ("visible",    gmx_bool(background.visible && !name.is_empty()).to_string()),
+    ("foreground", gmx_bool(background.foreground).to_string()),
+    ("name",       name),
+    ("x",          background.x.to_string()),
+    ("y",          background.y.to_string()),
+    ("htiled",     gmx_bool(background.htiled).to_string()),
+    ("vtiled",     gmx_bool(background.vtiled).to_string()),
+    ("hspeed",     background.hspeed.to_string()),
+    ("vspeed",     background.vspeed.to_string()),
+    ("stretch",    gmx_bool(background.stretch).to_string()),
+  ]);
+}
+
+fn export_view(xml: &mut XmlBuilder, view: Option<&View>) {
+  let empty = View { visible: false, ..View::default() };
+  let view = view.unwrap_or(&empty);
+  let follow = view.follow.as_ref().map(|o| asset_name_in("object", o)).unwrap_or(String::from("<undefined>"));
+
+  xml.empty_with("view", &[
+    ("visible", gmx_bool(view.visible).to_string()),
+    ("objName", follow),
+    ("xview",   view.view_x.to_string()),
+    ("yview",   view.view_y.to_string()),
+    ("wview",   view.view_w.to_string()),
+    ("hview",   view.view_h.to_string()),
+    ("xport",   view.port_x.to_string()),
+    ("yport",   view.port_y.to_string()),
+    ("wport",   view.port_w.to_string()),
+    ("hport",   view.port_h.to_string()),
+    ("hborder", view.hborder.to_string()),
+    ("vborder", view.vborder.to_string()),
+    ("hspeed",  view.hspeed.to_string()),
+    ("vspeed",  view.vspeed.to_string()),
+  ]);
+}
+
+fn gmx_bool(b: bool) -> i32 {
+  if b { -1 } else { 0 }
+}
+
+/// Game Maker keeps real numbers as 32 bit floats, and writes 15 significant digits of them.
+fn gmx_real(value: f64) -> String {
+  let value = value as f32 as f64;
+  if value == 0.0 {
+    return String::from("0")
+  }
+
+  let decimals = (14 - value.abs().log10().floor() as i64).max(0) as usize;
+  let text     = format!("{:.*}", decimals, value);
+
+  if text.contains('.') {
+    String::from(text.trim_end_matches('0').trim_end_matches('.'))
+  } else {
+    text
+  }
+}
+
+/// MGL colors are written as `0xRRGGBB`, Game Maker stores them as `0xBBGGRR`.
+fn gmx_colour(color: u32) -> u32 {
+  let red   = (color >> 16) & 0xff;
+  let green = (color >> 8)  & 0xff;
+  let blue  =  color        & 0xff;
+  (blue << 16) | (green << 8) | red
+}
@@ -0,0 +1,922 @@
+//! Writes a `Project` as a Game Maker Studio 2 project: a `<name>.yyp` manifest,
+//! one directory per resource holding its `.yy` description, and the folder views
+//! that mirror the project's directories.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::ast::*;
+use crate::error::*;
+use crate::event::Event;
+use crate::compiler::json::*;
+use crate::compiler::guid::Guid;
+use crate::compiler::export::*;
+use crate::compiler::script::{build_script_for, GmlFeatures};
+use crate::resources::project::{Project, Item, Items};
+use crate::resources::script::Script;
+use crate::resources::object::{Object, PhysicsShape};
+use crate::resources::sprite::Sprite;
+use crate::resources::sound::{Sound, SoundKind};
+use crate::resources::font::Font;
+use crate::resources::path::Path;
+use crate::resources::background::Background;
+use crate::resources::room::{Room, RoomBackground, Tile, View, InstanceItem, ROOM_VIEWS};
+use crate::resources::instance::Instance;
+
+/// Only the default groups exist in the projects written here, every resource goes in them.
+const DEFAULT_TEXTURE_GROUP: &str = "1225f6b0-ac20-43bd-a82e-be73fa0b6f4f";
+const DEFAULT_AUDIO_GROUP:   &str = "7b2c4976-1e09-44e5-8256-c527145e03bb";
+
+/// Layers go from -16000 to 16000, the room color is under everything, then
+/// the backgrounds and the tiles, and the foregrounds are over everything.
+const COLOR_DEPTH:      i64 = 16000;
+const BACKGROUND_DEPTH: i64 = 15500;
+const TILE_DEPTH:       i64 = 15000;
+
+pub fn export_gms2(project: &Project, context: &ExportContext) -> Result<PathBuf> {
+  let directory = context.output_path(&[&context.name]);
+  let mut exporter = Gms2Exporter {
+    context,
+    directory: directory.clone(),
+    resources: Vec::new(),
+  };
+
+  exporter.export(project)?;
+  Ok(directory)
+}
+
+pub fn resource_guid(name: &ResourceName) -> Guid {
+  Guid::from_name(&name.to_string())
+}
+
+fn nil() -> String {
+  Guid::nil().to_string()
+}
+
+struct Gms2Exporter<'a> {
+  context: &'a ExportContext,
+  directory: PathBuf,
+  resources: Vec<Json>,
+}
+
+/// The resource type, the directory its files go in and the name the IDE shows for it.
+type Kind<'a> = (&'a str, &'a str, &'a str);
+
+impl<'a> Gms2Exporter<'a> {
+  fn export(&mut self, project: &Project) -> Result<()> {
+    let folders = [
+      self.export_folder(("GMSprite", "sprites", "ResourceTree_Sprites"), "", &sprites_with_backgrounds(project), |e, n, s| {
+        e.export_sprite(n, s)
+      })?,
+
+      self.export_folder(("GMTileSet", "tilesets", "ResourceTree_Tilesets"), "", &tilesets(&project.backgrounds), |e, n, b| {
+        e.export_tileset(n, b)
+      })?,
+
+      self.export_folder(("GMSound", "sounds", "ResourceTree_Sounds"), "", &project.sounds, |e, n, s| {
+        e.export_sound(n, s)
+      })?,
+
+      self.export_folder(("GMPath", "paths", "ResourceTree_Paths"), "", &project.paths, |e, n, p| {
+        e.export_path(n, p)
+      })?,
+
+      self.export_folder(("GMScript", "scripts", "ResourceTree_Scripts"), "", &project.scripts, |e, n, s| {
+        e.export_script(n, s)
+      })?,
+
+      self.export_folder(("GMFont", "fonts", "ResourceTree_Fonts"), "", &project.fonts, |e, n, f| {
+        e.export_font(n, f)
+      })?,
+
+      self.export_folder(("GMObject", "objects", "ResourceTree_Objects"), "", &project.objects, |e, n, o| {
+        e.export_object(n, o)
+      })?,
+
+      self.export_folder(("GMRoom", "rooms", "ResourceTree_Rooms"), "", &project.rooms, |e, n, r| {
+        e.export_room(n, r)
+      })?,
+    ];
+
+    let root = Guid::from_name("view::");
+    self.write_view(root, json_object! {
+      "id"          => root.to_string(),
+      "modelName"   => "GMFolder",
+      "mvc"         => "1.1",
+      "name"        => root.to_string(),
+      "children"    => Json::array(folders.iter().map(Guid::to_string).collect()),
+      "filterType"  => "root",
+      "folderName"  => "Default",
+      "isDefaultView" => true,
+      "localisedFolderName" => "",
+    })?;
+
+    let project_id = Guid::from_name(&format!("project::{}", self.context.name));
+    let resources  = self.resources.clone();
+
+    let yyp = json_object! {
+      "id"           => project_id.to_string(),
+      "modelName"    => "GMProject",
+      "mvc"          => "1.0",
+      "IsDnDProject" => false,
+      "configs"      => Json::Array(vec![]),
+      "option_ecma"  => false,
+      "parentProject" => json_object! {
+        "id"        => Guid::from_name(&format!("project-parent::{}", self.context.name)).to_string(),
+        "modelName" => "GMProjectParent",
+        "mvc"       => "1.0",
+        "alteredResources" => Json::Array(vec![]),
+        "hiddenResources"  => Json::Array(vec![]),
+        "projectPath"      => "${base_project}",
+      },
+      "resources"    => Json::Array(resources),
+      "script_order" => Json::Array(vec![]),
+      "tutorial"     => "",
+    };
+
+    let file_name = format!("{}.yyp", self.context.name);
+    write_file(&self.directory.join(file_name), &yyp.pretty())
+  }
+
+  fn register(&mut self, id: Guid, path: &str, resource_type: &str) {
+    self.resources.push(json_object! {
+      "Key"   => id.to_string(),
+      "Value" => json_object! {
+        "id"           => Guid::from_name(&format!("entry::{}", id)).to_string(),
+        "resourcePath" => path,
+        "resourceType" => resource_type,
+      },
+    });
+  }
+
+  fn write_resource(&self, parts: &[&str], json: Json) -> Result<()> {
+    let mut path = self.directory.clone();
+    for part in parts {
+      path.push(part);
+    }
+    write_file(&path, &json.pretty())
+  }
+
+  fn write_view(&mut self, id: Guid, json: Json) -> Result<()> {
+    self.write_resource(&["views", &format!("{}.yy", id)], json)?;
+    self.register(id, &format!("views\\{}.yy", id), "GMFolder");
+    Ok(())
+  }
+
+
+  /// Folders are identified by their path, `Item::Group`s become nested folders.
+  fn export_folder<T, F>(&mut self, kind: Kind, path: &str, items: &Items<T>, export: F) -> Result<Guid>
+    where F: Fn(&mut Self, &ResourceName, &T) -> Result<Guid> + Copy {
+
+    let (filter, directory, localised) = kind;
+    let path     = format!("{}/{}", path, directory);
+    let id       = Guid::from_name(&format!("view::{}", path));
+    let is_top   = path.matches('/').count() == 1;
+    let mut children = Vec::new();
+
+    for item in items {
+      match item {
+        Item::Group(name, sub_items) => {
+          let sub_kind = (filter, &name[..], "");
+          children.push(self.export_folder(sub_kind, &path, sub_items, export)?);
+        }
+
+        Item::File(name, resource) => {
+          children.push(export(self, name, resource)?);
+        }
+      }
+    }
+
+    self.write_view(id, json_object! {
+      "id"            => id.to_string(),
+      "modelName"     => "GMFolder",
+      "mvc"           => "1.1",
+      "name"          => id.to_string(),
+      "children"      => Json::array(children.iter().map(Guid::to_string).collect()),
+      "filterType"    => filter,
+      "folderName"    => directory,
+      "isDefaultView" => false,
+      "localisedFolderName" => if is_top { localised } else { "" },
+    })?;
+    Ok(id)
+  }
+
+
+  fn export_script(&mut self, name: &ResourceName, script: &Script) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+
+    let code = self.directory.join("scripts").join(&name).join(format!("{}.gml", name));
+    write_file(&code, &build_script_for(script.clone(), GmlFeatures::GMS2)?)?;
+
+    self.write_resource(&["scripts", &name, &format!("{}.yy", name)], json_object! {
+      "id"              => id.to_string(),
+      "modelName"       => "GMScript",
+      "mvc"             => "1.0",
+      "name"            => &name,
+      "IsCompatibility" => false,
+      "IsDnD"           => false,
+    })?;
+
+    self.register(id, &format!("scripts\\{}\\{}.yy", name, name), "GMScript");
+    Ok(id)
+  }
+
+
+  fn export_object(&mut self, name: &ResourceName, object: &Object) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+    let sprite = object.sprite.as_ref().map(|s| resource_guid(&s.qualified("sprite")).to_string());
+    let mask   = object.mask.as_ref().map(|s| resource_guid(&s.qualified("sprite")).to_string());
+    let parent = object.parent.as_ref().map(|p| resource_guid(&p.content.qualified("object")).to_string());
+    let physics = &object.physics;
+    let mut events = Vec::new();
+
+    for (event, script) in &object.events {
+      let file = format!("{}.gml", event_file_name(event));
+      let code = format!("{}();\n", asset_name_in("script", script));
+      write_file(&self.directory.join("objects").join(&name).join(file), &code)?;
+
+      let collision = match event {
+        Event::Collision(other) => resource_guid(&other.qualified("object")).to_string(),
+        _ => nil()
+      };
+
+      events.push(json_object! {
+        "id"                => Guid::from_name(&format!("{}#{}", id, event_file_name(event))).to_string(),
+        "modelName"         => "GMEvent",
+        "mvc"               => "1.0",
+        "IsDnD"             => false,
+        "collisionObjectId" => collision,
+        "enumb"             => event.event_number(),
+        "eventtype"         => event.event_type(),
+        "m_owner"           => id.to_string(),
+      });
+    }
+
+    let points = physics.shape_points.iter().enumerate().map(|(index, point)| {
+      json_object! {
+        "id"        => Guid::from_name(&format!("{}#point{}", id, index)).to_string(),
+        "modelName" => "GMPoint",
+        "mvc"       => "1.0",
+        "x"         => point.x,
+        "y"         => point.y,
+      }
+    }).collect::<Vec<_>>();
+
+    // objects have no depth in GMS2, instances get theirs from their layer
+    self.write_resource(&["objects", &name, &format!("{}.yy", name)], json_object! {
+      "id"                    => id.to_string(),
+      "modelName"             => "GMObject",
+      "mvc"                   => "1.0",
+      "name"                  => &name,
+      "eventList"             => events,
+      "maskSpriteId"          => mask.unwrap_or(nil()),
+      "overriddenProperties"  => Json::Null,
+      "parentObjectId"        => parent.unwrap_or(nil()),
+      "persistent"            => object.persistent,
+      "physicsAngularDamping" => physics.angular_damping,
+      "physicsDensity"        => physics.density,
+      "physicsFriction"       => physics.friction,
+      "physicsGroup"          => physics.group,
+      "physicsKinematic"      => physics.kinematic,
+      "physicsLinearDamping"  => physics.linear_damping,
+      "physicsObject"         => physics.enabled,
+      "physicsRestitution"    => physics.restitution,
+      "physicsSensor"         => physics.sensor,
+      "physicsShape"          => physics.shape.unwrap_or(PhysicsShape::Box) as u32,
+      "physicsShapePoints"    => if points.is_empty() { Json::Null } else { Json::from(points) },
+      "physicsStartAwake"     => physics.awake,
+      "properties"            => Json::Null,
+      "solid"                 => object.solid,
+      "spriteId"              => sprite.unwrap_or(nil()),
+      "visible"               => object.visible,
+    })?;
+
+    self.register(id, &format!("objects\\{}\\{}.yy", name, name), "GMObject");
+    Ok(id)
+  }
+
+
+  fn export_sprite(&mut self, name: &ResourceName, sprite: &Sprite) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+    let layer = Guid::from_name(&format!("{}#layer", id));
+    let directory = self.directory.join("sprites").join(&name);
+    let (x, y) = sprite.origin_point();
+    let mut frames = Vec::new();
+
+    for (index, frame) in sprite.frames.iter().enumerate() {
+      let frame_id = Guid::from_name(&format!("{}#frame{}", id, index));
+
+      if let Some(data) = &frame.data {
+        let source = self.context.source_path(data);
+        copy_file(&source, &directory.join(format!("{}.png", frame_id)))?;
+        copy_file(&source, &directory.join("layers").join(frame_id.to_string()).join(format!("{}.png", layer)))?;
+      }
+
+      frames.push(json_object! {
+        "id"        => frame_id.to_string(),
+        "modelName" => "GMSpriteFrame",
+        "mvc"       => "1.0",
+        "SpriteId"  => id.to_string(),
+        "compositeImage" => json_object! {
+          "id"        => Guid::from_name(&format!("{}#composite", frame_id)).to_string(),
+          "modelName" => "GMSpriteImage",
+          "mvc"       => "1.0",
+          "FrameId"   => frame_id.to_string(),
+          "LayerId"   => nil(),
+        },
+        "images" => vec![json_object! {
+          "id"        => Guid::from_name(&format!("{}#image", frame_id)).to_string(),
+          "modelName" => "GMSpriteImage",
+          "mvc"       => "1.0",
+          "FrameId"   => frame_id.to_string(),
+          "LayerId"   => layer.to_string(),
+        }],
+      });
+    }
+
+    self.write_resource(&["sprites", &name, &format!("{}.yy", name)], json_object! {
+      "id"               => id.to_string(),
+      "modelName"        => "GMSprite",
+      "mvc"              => "1.12",
+      "name"             => &name,
+      "For3D"            => sprite.texture.used_for_3d,
+      "HTile"            => sprite.texture.horizontal,
+      "VTile"            => sprite.texture.vertical,
+      "bbox_bottom"      => sprite.bounding_box.bottom,
+      "bbox_left"        => sprite.bounding_box.left,
+      "bbox_right"       => sprite.bounding_box.right,
+      "bbox_top"         => sprite.bounding_box.top,
+      "bboxmode"         => sprite.bounding_box.mode as i32,
+      "colkind"          => sprite.collision_kind as i32,
+      "coltolerance"     => sprite.collision_tolerance,
+      "edgeFiltering"    => false,
+      "frames"           => frames,
+      "gridX"            => 0,
+      "gridY"            => 0,
+      "height"           => sprite.height,
+      "layers"           => vec![json_object! {
+        "id"         => layer.to_string(),
+        "modelName"  => "GMImageLayer",
+        "mvc"        => "1.0",
+        "SpriteId"   => id.to_string(),
+        "blendMode"  => 0,
+        "isLocked"   => false,
+        "name"       => "default",
+        "opacity"    => 100,
+        "visible"    => true,
+      }],
+      "origin"           => if sprite.origin.center { 4 } else { 9 },
+      "originLocked"     => false,
+      "playbackSpeed"    => 15,
+      "playbackSpeedType" => 0,
+      "premultiplyAlpha" => false,
+      "sepmasks"         => sprite.separate_masks,
+      "swatchColours"    => Json::Null,
+      "swfPrecision"     => 2.525,
+      "textureGroupId"   => DEFAULT_TEXTURE_GROUP,
+      "type"             => 0,
+      "width"            => sprite.width,
+      "xorig"            => x,
+      "yorig"            => y,
+    })?;
+
+    self.register(id, &format!("sprites\\{}\\{}.yy", name, name), "GMSprite");
+    Ok(id)
+  }
+
+
+  fn export_sound(&mut self, name: &ResourceName, sound: &Sound) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+    let data = format!("{}{}", name, file_extension(&sound.data));
+
+    if !sound.data.as_os_str().is_empty() {
+      let target = self.directory.join("sounds").join(&name).join(&data);
+      copy_file(&self.context.source_path(&sound.data), &target)?;
+    }
+
+    self.write_resource(&["sounds", &name, &format!("{}.yy", name)], json_object! {
+      "id"             => id.to_string(),
+      "modelName"      => "GMSound",
+      "mvc"            => "1.0",
+      "name"           => &name,
+      "audioGroupGuid" => DEFAULT_AUDIO_GROUP,
+      "bitDepth"       => if sound.bit_depth == 8 { 0 } else { 1 },
+      "bitRate"        => sound.bit_rate,
+      "kind"           => if sound.compress { 1 } else { 0 },
+      "preload"        => sound.preload,
+      "sampleRate"     => sound.sample_rate,
+      "type"           => if sound.kind == SoundKind::ThreeDimensional { 2 } else { 0 },
+      "volume"         => sound.volume,
+    })?;
+
+    self.register(id, &format!("sounds\\{}\\{}.yy", name, name), "GMSound");
+    Ok(id)
+  }
+
+
+  /// The image of the background is exported as a sprite with the same name.
+  fn export_tileset(&mut self, name: &ResourceName, background: &Background) -> Result<Guid> {
+    let sprite = resource_guid(name);
+    let id = tileset_guid(name);
+    let name = format!("{}_tileset", asset_name(name));
+    let tiles = (0..background.tile_count()).map(Json::from).collect::<Vec<_>>();
+
+    self.write_resource(&["tilesets", &name, &format!("{}.yy", name)], json_object! {
+      "id"                    => id.to_string(),
+      "modelName"             => "GMTileSet",
+      "mvc"                   => "1.11",
+      "name"                  => &name,
+      "auto_tile_sets"        => Json::Array(vec![]),
+      "macroPageTiles"        => json_object! {
+        "SerialiseData"     => Json::Null,
+        "SerialiseHeight"   => 0,
+        "SerialiseWidth"    => 0,
+        "TileSerialiseData" => Json::Array(vec![]),
+      },
+      "out_columns"           => 0,
+      "out_tilehborder"       => 2,
+      "out_tilevborder"       => 2,
+      "spriteId"              => sprite.to_string(),
+      "sprite_no_export"      => true,
+      "textureGroupId"        => DEFAULT_TEXTURE_GROUP,
+      "tile_animation"        => json_object! {
+        "AnimationCreationOrder" => Json::Null,
+        "FrameData"              => Json::from(tiles),
+        "SerialiseFrameCount"    => 1,
+      },
+      "tile_animation_frames" => Json::Array(vec![]),
+      "tile_animation_speed"  => 15,
+      "tile_count"            => background.tile_count(),
+      "tileheight"            => background.tile_height,
+      "tilehsep"              => background.horizontal_separation,
+      "tilevsep"              => background.vertical_separation,
+      "tilewidth"             => background.tile_width,
+      "tilexoff"              => background.horizontal_offset,
+      "tileyoff"              => background.vertical_offset,
+    })?;
+
+    self.register(id, &format!("tilesets\\{}\\{}.yy", name, name), "GMTileSet");
+    Ok(id)
+  }
+
+
+  fn export_path(&mut self, name: &ResourceName, path: &Path) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+
+    let points = path.points.iter().enumerate().map(|(index, point)| {
+      json_object! {
+        "id"        => Guid::from_name(&format!("{}#point{}", id, index)).to_string(),
+        "modelName" => "GMPathPoint",
+        "mvc"       => "1.0",
+        "speed"     => point.speed,
+        "x"         => point.x,
+        "y"         => point.y,
+      }
+    }).collect::<Vec<_>>();
+
+    self.write_resource(&["paths", &name, &format!("{}.yy", name)], json_object! {
+      "id"        => id.to_string(),
+      "modelName" => "GMPath",
+      "mvc"       => "1.0",
+      "name"      => &name,
+      "closed"    => path.closed,
+      "hsnap"     => 0,
+      "kind"      => if path.smooth { 1 } else { 0 },
+      "points"    => Json::from(points),
+      "precision" => path.precision,
+      "vsnap"     => 0,
+    })?;
+
+    self.register(id, &format!("paths\\{}\\{}.yy", name, name), "GMPath");
+    Ok(id)
+  }
+
+
+  /// The glyphs are left for the IDE to generate.
+  fn export_font(&mut self, name: &ResourceName, font: &Font) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+
+    let ranges = font.character_ranges().iter().map(|range| {
+      json_object! {
+        "x" => range.first,
+        "y" => range.last,
+      }
+    }).collect::<Vec<_>>();
+
+    let style = match (font.bold, font.italic) {
+      (true,  true)  => "Bold Italic",
+      (true,  false) => "Bold",
+      (false, true)  => "Italic",
+      (false, false) => "Regular",
+    };
+
+    self.write_resource(&["fonts", &name, &format!("{}.yy", name)], json_object! {
+      "id"              => id.to_string(),
+      "modelName"       => "GMFont",
+      "mvc"             => "1.0",
+      "name"            => &name,
+      "AntiAlias"       => if font.antialiasing > 0 { 1 } else { 0 },
+      "TTFName"         => "",
+      "bold"            => font.bold,
+      "charset"         => font.charset,
+      "first"           => 0,
+      "fontName"        => &font.name,
+      "glyphs"          => Json::Array(vec![]),
+      "italic"          => font.italic,
+      "kerningPairs"    => Json::Array(vec![]),
+      "last"            => 0,
+      "ranges"          => Json::from(ranges),
+      "sampleText"      => "abcdef ABCDEF\n0123456789 .,<>\"'&!?",
+      "size"            => font.size,
+      "styleName"       => style,
+      "textureGroupId"  => DEFAULT_TEXTURE_GROUP,
+    })?;
+
+    self.register(id, &format!("fonts\\{}\\{}.yy", name, name), "GMFont");
+    Ok(id)
+  }
+
+
+  fn export_room(&mut self, name: &ResourceName, room: &Room) -> Result<Guid> {
+    let id = resource_guid(name);
+    let name = asset_name(name);
+    let mut instances = Vec::new();
+    let mut order     = Vec::new();
+
+    for (index, item) in room.instances.iter().enumerate() {
+      if let InstanceItem::Resolved(instance) = item {
+        let (instance_id, json) = self.export_instance(id, &name, index, instance)?;
+        order.push(Json::from(instance_id.to_string()));
+        instances.push(json);
+      }
+    }
+
+    let mut layers = vec![
+      json_object! {
+        "__type"    => "GMRInstanceLayer_Model:#YoYoStudio.MVCFormat",
+        "name"      => "Instances",
+        "id"        => Guid::from_name(&format!("{}#instances", id)).to_string(),
+        "depth"     => 0,
+        "grid_x"    => 32,
+        "grid_y"    => 32,
+        "hierarchyFrozen"      => false,
+        "hierarchyVisible"     => true,
+        "inheritLayerDepth"    => false,
+        "inheritLayerSettings" => false,
+        "inheritSubLayers"     => false,
+        "inheritVisibility"    => false,
+        "instances"            => instances,
+        "layers"               => Json::Array(vec![]),
+        "m_parentID"           => nil(),
+        "m_serialiseFrozen"    => false,
+        "modelName"            => "GMRInstanceLayer",
+        "mvc"                  => "1.0",
+        "userdefined_depth"    => false,
+        "visible"              => true,
+      },
+      json_object! {
+        "__type"    => "GMRBackgroundLayer_Model:#YoYoStudio.MVCFormat",
+        "name"      => "Background",
+        "id"        => Guid::from_name(&format!("{}#background", id)).to_string(),
+        "animationFPS"         => 15,
+        "animationSpeedType"   => "0",
+        "colour"               => json_object! { "Value" => gms2_colour(room.color, 1.0) },
+        "depth"                => COLOR_DEPTH,
+        "grid_x"               => 32,
+        "grid_y"               => 32,
+        "hierarchyFrozen"      => false,
+        "hierarchyVisible"     => true,
+        "hspeed"               => 0,
+        "htiled"               => false,
+        "inheritLayerDepth"    => false,
+        "inheritLayerSettings" => false,
+        "inheritSubLayers"     => false,
+        "inheritVisibility"    => false,
+        "layers"               => Json::Array(vec![]),
+        "m_parentID"           => nil(),
+        "m_serialiseFrozen"    => false,
+        "modelName"            => "GMRBackgroundLayer",
+        "mvc"                  => "1.0",
+        "spriteId"             => nil(),
+        "stretch"              => false,
+        "userdefined_animFPS"  => false,
+        "userdefined_depth"    => false,
+        "visible"              => true,
+        "vspeed"               => 0,
+        "vtiled"               => false,
+        "x"                    => 0,
+        "y"                    => 0,
+      },
+    ];
+
+    for (index, background) in room.backgrounds.iter().enumerate() {
+      if let Some(image) = &background.background {
+        layers.push(room_background_layer(id, index, image, background));
+      }
+    }
+
+    // tiles too deep for a layer end up on the deepest one, along with the tiles already there
+    let mut depths = BTreeMap::new();
+    for tile in &room.tiles {
+      depths.entry(tile.depth.clamp(-TILE_DEPTH, TILE_DEPTH)).or_insert_with(Vec::new).push(tile);
+    }
+
+    for (depth, tiles) in depths {
+      layers.push(tile_layer(id, depth, &tiles));
+    }
+
+    let views = (0..ROOM_VIEWS).map(|index| view(id, index, room.views.get(index))).collect::<Vec<_>>();
+    let code = room_creation_code(room, |index, angle| {
+      format!("camera_set_view_angle(view_camera[{}], {});\n", index, angle)
+    });
+
+    let mut creation_code = String::new();
+    if !code.is_empty() {
+      write_file(&self.directory.join("rooms").join(&name).join("RoomCreationCode.gml"), &code)?;
+      creation_code = format!("${{project_dir}}\\rooms\\{}\\RoomCreationCode.gml", name);
+    }
+
+    self.write_resource(&["rooms", &name, &format!("{}.yy", name)], json_object! {
+      "name"                     => &name,
+      "id"                       => id.to_string(),
+      "creationCodeFile"         => creation_code,
+      "inheritCode"              => false,
+      "inheritCreationOrder"     => false,
+      "inheritLayers"            => false,
+      "instanceCreationOrderIDs" => order,
+      "IsDnD"                    => false,
+      "layers"                   => layers,
+      "modelName"                => "GMRoom",
+      "parentId"                 => nil(),
+      "physicsSettings"          => json_object! {
+        "id"                     => Guid::from_name(&format!("{}#physics", id)).to_string(),
+        "inheritPhysicsSettings" => false,
+        "modelName"              => "GMRoomPhysicsSettings",
+        "PhysicsWorld"           => false,
+        "PhysicsWorldGravityX"   => 0,
+        "PhysicsWorldGravityY"   => 10,
+        "PhysicsWorldPixToMeters" => 0.1,
+        "mvc"                    => "1.0",
+      },
+      "roomSettings"             => json_object! {
+        "id"                  => Guid::from_name(&format!("{}#settings", id)).to_string(),
+        "Height"              => room.height,
+        "inheritRoomSettings" => false,
+        "modelName"           => "GMRoomSettings",
+        "persistent"          => room.persistent,
+        "mvc"                 => "1.0",
+        "Width"               => room.width,
+      },
+      "mvc"                      => "1.0",
+      "views"                    => views,
+      "viewSettings"             => json_object! {
+        "id"                  => Guid::from_name(&format!("{}#view-settings", id)).to_string(),
+        "clearDisplayBuffer"  => room.clear_display_buffer,
+        "clearViewBackground" => room.clear_view_background,
+        "enableViews"         => room.enable_views,
+        "inheritViewSettings" => false,
+        "modelName"           => "GMRoomViewSettings",
+        "mvc"                 => "1.0",
+      },
+    })?;
+
+    self.register(id, &format!("rooms\\{}\\{}.yy", name, name), "GMRoom");
+    Ok(id)
+  }
+
+  fn export_instance(&mut self,
+                     room: Guid,
+                     room_name: &str,
+                     index: usize,
+                     instance: &Instance) -> Result<(Guid, Json)> {
+    let id = Guid::from_name(&format!("{}#instance{}", room, index));
+    let instance_name = format!("inst_{}", id.to_string()[..8].to_uppercase());
+    let mut code_file = String::new();
+
+    if let Some(code) = &instance.creation_code {
+      code_file = format!("InstanceCreationCode_{}.gml", instance_name);
+      let code = format!("{}();\n", asset_name_in("script", code));
+      write_file(&self.directory.join("rooms").join(room_name).join(&code_file), &code)?;
+    }
+
+    Ok((id, json_object! {
+      "name"                 => &instance_name,
+      "id"                   => id.to_string(),
+      "colour"               => json_object! { "Value" => gms2_colour(instance.color, instance.alpha) },
+      "creationCodeFile"     => code_file,
+      "creationCodeType"     => if instance.creation_code.is_some() { ".gml" } else { "" },
+      "ignore"               => false,
+      "inheritCode"          => false,
+      "inheritItemSettings"  => false,
+      "IsDnD"                => false,
+      "m_originalParentID"   => nil(),
+      "m_serialiseFrozen"    => false,
+      "modelName"            => "GMRInstance",
+      "name_with_no_file_rename" => &instance_name,
+      "objId"                => resource_guid(&instance.object.qualified("object")).to_string(),
+      "properties"           => Json::Null,
+      "rotation"             => instance.rotation,
+      "scaleX"               => instance.scale,
+      "scaleY"               => instance.scale,
+      "mvc"                  => "1.0",
+      "x"                    => instance.x,
+      "y"                    => instance.y,
+    }))
+  }
+}
+
+fn event_file_name(event: &Event) -> String {
+  let kind = match event {
+    Event::Create        => "Create",
+    Event::Destroy       => "Destroy",
+    Event::Alarm(_)      => "Alarm",
+    Event::Step(_)       => "Step",
+    Event::Keyboard(_)   => "Keyboard",
+    Event::KeyPress(_)   => "KeyPress",
+    Event::KeyRelease(_) => "KeyRelease",
+    Event::Mouse(_)      => "Mouse",
+    Event::Collision(_)  => "Collision",
+    Event::Other(_)      => "Other",
+    Event::Draw(_)       => "Draw",
+  };
+
+  if let Event::Collision(object) = event {
+    format!("{}_{}", kind, resource_guid(&object.qualified("object")))
+  } else {
+    format!("{}_{}", kind, event.event_number())
+  }
+}
+
+/// GMS2 stores colors as `0xAABBGGRR`.
+fn gms2_colour(color: u32, alpha: f64) -> u64 {
+  let alpha = (alpha.clamp(0.0, 1.0) * 255.0).round() as u64;
+  let red   = ((color >> 16) & 0xff) as u64;
+  let green = ((color >> 8)  & 0xff) as u64;
+  let blue  = ( color        & 0xff) as u64;
+  (alpha << 24) | (blue << 16) | (green << 8) | red
+}
+
+fn tileset_guid(background: &ResourceName) -> Guid {
+  Guid::from_name(&format!("{}#tileset", resource_guid(background)))
+}
+
+/// The images of the backgrounds go in their own folder among the sprites.
+fn sprites_with_backgrounds(project: &Project) -> Items<Sprite> {
+  let mut sprites = project.sprites.clone();
+
+  if !project.backgrounds.is_empty() {
+    sprites.push(Item::Group(String::from("backgrounds"), map_items(&project.backgrounds, Background::sprite)));
+  }
+  sprites
+}
+
+fn map_items<T, U>(items: &Items<T>, f: fn(&T) -> U) -> Items<U> {
+  items.iter().map(|item| {
+    match item {
+      Item::File(name, resource)   => Item::File(name.clone(), f(resource)),
+      Item::Group(name, sub_items) => Item::Group(name.clone(), map_items(sub_items, f)),
+    }
+  }).collect()
+}
+
+/// The backgrounds used as tilesets, leaving out the groups without any.
+fn tilesets(backgrounds: &Items<Background>) -> Items<Background> {
+  backgrounds.iter().filter_map(|item| {
+    match item {
+      Item::File(_, background) if !background.use_as_tileset => None,
+
+      Item::Group(name, sub_items) => {
+        let sub_items = tilesets(sub_items);
+        if sub_items.is_empty() { None } else { Some(Item::Group(name.clone(), sub_items)) }
+      }
+
+      item => Some(item.clone()),
+    }
+  }).collect()
+}
+
+fn view(room: Guid, index: usize, view: Option<&View>) -> Json {
+  let empty = View { visible: false, ..View::default() };
+  let view = view.unwrap_or(&empty);
+  let follow = view.follow.as_ref().map(|o| resource_guid(&o.qualified("object")).to_string());
+
+  json_object! {
+    "id"        => Guid::from_name(&format!("{}#view{}", room, index)).to_string(),
+    "hborder"   => view.hborder,
+    "hport"     => view.port_h,
+    "hspeed"    => view.hspeed,
+    "hview"     => view.view_h,
+    "inherit"   => false,
+    "modelName" => "GMRView",
+    "objId"     => follow.unwrap_or(nil()),
+    "mvc"       => "1.0",
+    "vborder"   => view.vborder,
+    "visible"   => view.visible,
+    "vspeed"    => view.vspeed,
+    "wport"     => view.port_w,
+    "wview"     => view.view_w,
+    "xport"     => view.port_x,
+    "xview"     => view.view_x,
+    "yport"     => view.port_y,
+    "yview"     => view.view_y,
+  }
+}
+
+fn room_background_layer(room: Guid, index: usize, image: &ResourceName, background: &RoomBackground) -> Json {
+  let depth = if background.foreground { -BACKGROUND_DEPTH } else { BACKGROUND_DEPTH };
+
+  json_object! {
+    "__type"    => "GMRBackgroundLayer_Model:#YoYoStudio.MVCFormat",
+    "name"      => format!("Background_{}", index),
+    "id"        => Guid::from_name(&format!("{}#background{}", room, index)).to_string(),
+    "animationFPS"         => 15,
+    "animationSpeedType"   => "0",
+    "colour"               => json_object! { "Value" => gms2_colour(0xffffff, 1.0) },
+    "depth"                => depth - index as i64,
+    "grid_x"               => 32,
+    "grid_y"               => 32,
+    "hierarchyFrozen"      => false,
+    "hierarchyVisible"     => true,
+    "hspeed"               => background.hspeed,
+    "htiled"               => background.htiled,
+    "inheritLayerDepth"    => false,
+    "inheritLayerSettings" => false,
+    "inheritSubLayers"     => false,
+    "inheritVisibility"    => false,
+    "layers"               => Json::Array(vec![]),
+    "m_parentID"           => nil(),
+    "m_serialiseFrozen"    => false,
+    "modelName"            => "GMRBackgroundLayer",
+    "mvc"                  => "1.0",
+    "spriteId"             => resource_guid(&image.qualified("background")).to_string(),
+    "stretch"              => background.stretch,
+    "userdefined_animFPS"  => false,
+    "userdefined_depth"    => true,
+    "visible"              => background.visible,
+    "vspeed"               => background.vspeed,
+    "vtiled"               => background.vtiled,
+    "x"                    => background.x,
+    "y"                    => background.y,
+  }
+}
+
+/// Tiles of the same depth share an asset layer, as GMS2 does with the tiles of imported GMS1 rooms.
+fn tile_layer(room: Guid, depth: i64, tiles: &[&Tile]) -> Json {
+  let layer = Guid::from_name(&format!("{}#tiles{}", room, depth));
+
+  let assets = tiles.iter().enumerate().map(|(index, tile)| {
+    let id = Guid::from_name(&format!("{}#tile{}", layer, index));
+    let background = tile.background.as_ref().map(|b| resource_guid(&b.qualified("background")).to_string());
+
+    json_object! {
+      "__type"    => "GMRTile_Model:#YoYoStudio.MVCFormat",
+      "name"      => format!("tile_{}", id.to_string()[..8].to_uppercase()),
+      "id"        => id.to_string(),
+      "colour"              => json_object! { "Value" => gms2_colour(0xffffff, 1.0) },
+      "h"                   => tile.height,
+      "ignore"              => false,
+      "inheritItemSettings" => false,
+      "m_originalParentID"  => nil(),
+      "m_serialiseFrozen"   => false,
+      "modelName"           => "GMRTile",
+      "mvc"                 => "1.1",
+      "spriteId"            => background.unwrap_or(nil()),
+      "u0"                  => tile.left,
+      "u1"                  => tile.left + tile.width,
+      "v0"                  => tile.top,
+      "v1"                  => tile.top + tile.height,
+      "w"                   => tile.width,
+      "x"                   => tile.x,
+      "y"                   => tile.y,
+    }
+  }).collect::<Vec<_>>();
+
+  json_object! {
+    "__type"    => "GMRAssetLayer_Model:#YoYoStudio.MVCFormat",
+    "name"      => format!("Tiles_{}", depth),
+    "id"        => layer.to_string(),
+    "assets"               => assets,
+    "depth"                => depth,
+    "grid_x"               => 32,
+    "grid_y"               => 32,
+    "hierarchyFrozen"      => false,
+    "hierarchyVisible"     => true,
+    "inheritLayerDepth"    => false,
+    "inheritLayerSettings" => false,
+    "inheritSubLayers"     => false,
+    "inheritVisibility"    => false,
+    "layers"               => Json::Array(vec![]),
+    "m_parentID"           => nil(),
+    "m_serialiseFrozen"    => false,
+    "modelName"            => "GMRAssetLayer",
+    "mvc"                  => "1.0",
+    "userdefined_depth"    => true,
+    "visible"              => true,
+  }
+}
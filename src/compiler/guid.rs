@@ -0,0 +1,43 @@
+use std::fmt;
+use sha1::{Sha1, Digest};
+
+/// Namespace for the name based (version 5) GUIDs of generated resources.
+const MGL_NAMESPACE: [u8; 16] = [
+  0x6d, 0x67, 0x6c, 0x2d, 0x9b, 0x1e, 0x4c, 0x57,
+  0x8a, 0x3e, 0x0f, 0x5d, 0x21, 0xc4, 0x7b, 0x90,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guid([u8; 16]);
+
+impl Guid {
+  pub fn nil() -> Guid {
+    Guid([0; 16])
+  }
+
+  /// The same name always gives the same GUID, so exporting a project twice
+  /// doesn't make every file change.
+  pub fn from_name(name: &str) -> Guid {
+    let mut hasher = Sha1::new();
+    hasher.input(MGL_NAMESPACE);
+    hasher.input(name.as_bytes());
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.result()[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x50;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    Guid(bytes)
+  }
+}
+
+impl fmt::Display for Guid {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, byte) in self.0.iter().enumerate() {
+      if i == 4 || i == 6 || i == 8 || i == 10 {
+        write!(f, "-")?;
+      }
+      write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+  }
+}
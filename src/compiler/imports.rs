@@ -0,0 +1,449 @@
+//! Resolves `module` and `import` declarations.
+//!
+//!   module player
+//!   import sprite::player::idle
+//!   import sound::player::jump as jump_sound
+//!
+//! An import introduces an alias for a resource (or a whole module) declared
+//! somewhere in the project, the alias is then replaced by the full name in
+//! every expression of the file. Inside a function, the arguments, `var`s and
+//! `for` variables shadow the aliases with the same name.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::file_reader::*;
+use crate::resources::project::{Module, module_add, full_name, full_name_for};
+
+type Aliases = HashMap<String, ResourceName>;
+
+/// The module each resource of the project was declared in.
+type Declared = HashMap<ResourceName, Module>;
+
+/// For each module, the modules it imports from and the first import doing so.
+type Dependencies = BTreeMap<Module, BTreeMap<Module, IExpr>>;
+
+pub fn resolve_imports(tree: AstFileTree) -> TopResult<AstFileTree> {
+  let mut declared     = Declared::new();
+  let mut dependencies = Dependencies::new();
+  let mut errors       = Vec::new();
+
+  collect_declarations(&tree, &mut declared);
+  check_imports(&tree, &declared, &mut dependencies, &mut errors);
+  errors.append(&mut find_cycles(&dependencies));
+
+  if errors.is_empty() {
+    Ok(rename_tree(tree))
+  } else {
+    Err(errors)
+  }
+}
+
+/// Files without a `module` declaration are in the top module.
+pub fn file_module(top: &Top) -> Module {
+  for declaration in &top.declarations {
+    if let Declaration::Module(module) = declaration {
+      return module.names.clone()
+    }
+  }
+  Module::new()
+}
+
+fn collect_declarations(tree: &AstFileTree, declared: &mut Declared) {
+  match tree {
+    AstFileTree::Root(box subtree) => collect_declarations(subtree, declared),
+
+    AstFileTree::Node(_, children) => {
+      for child in children {
+        collect_declarations(child, declared);
+      }
+    }
+
+    AstFileTree::Leaf(_, top) => {
+      let module = file_module(top);
+      let mut declare = |name| { declared.insert(name, module.clone()); };
+
+      for declaration in &top.declarations {
+        match declaration {
+          Declaration::Function(function) => {
+            declare(full_name_for("script", &function.name, &module));
+          }
+
+          Declaration::Instance(instance) => {
+            declare(full_name_for("instance", &instance.name, &module));
+          }
+
+          Declaration::Constant(constant) => {
+            declare(full_name_for("const", &constant.name, &module));
+          }
+
+          Declaration::Enum(enumeration) => {
+            let enum_module = module_add(&module, enumeration.name.clone());
+
+            for variant in &enumeration.variants {
+              declare(full_name_for("const", &variant.name.content, &enum_module));
+            }
+          }
+
+          Declaration::Resource(resource) => {
+            let sub_module = module_add(&module, resource.name.clone());
+            declare(full_name(resource, &module));
+
+            for method in &resource.methods {
+              declare(full_name_for("script", &method.name, &sub_module));
+            }
+
+            for instance in &resource.instances {
+              declare(full_name_for("instance", &instance.name, &sub_module));
+            }
+
+            for constant in &resource.constants {
+              declare(full_name_for("const", &constant.name, &sub_module));
+            }
+          }
+
+          Declaration::Module(_) | Declaration::Import(_) => {}
+        }
+      }
+    }
+  }
+}
+
+fn check_imports(tree:         &AstFileTree,
+                 declared:     &Declared,
+                 dependencies: &mut Dependencies,
+                 errors:       &mut Vec<MglError>) {
+  match tree {
+    AstFileTree::Root(box subtree) => check_imports(subtree, declared, dependencies, errors),
+
+    AstFileTree::Node(_, children) => {
+      for child in children {
+        check_imports(child, declared, dependencies, errors);
+      }
+    }
+
+    AstFileTree::Leaf(_, top) => {
+      let module = file_module(top);
+
+      for declaration in &top.declarations {
+        if let Declaration::Import(import) = declaration {
+          let target  = import.resource_name();
+          let modules = declared.iter()
+                                .filter(|(name, _)| name.starts_with(&target))
+                                .map(|(_, module)| module)
+                                .collect::<Vec<_>>();
+
+          if modules.is_empty() {
+            errors.push(MglError::MissingImport { import: import.path.clone() });
+          }
+
+          for imported_module in modules {
+            if imported_module != &module {
+              dependencies.entry(module.clone())
+                          .or_default()
+                          .entry(imported_module.clone())
+                          .or_insert(import.path.clone());
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+  InProgress,
+  Done,
+}
+
+/// Every cycle is reported once, at the import that closes it.
+fn find_cycles(dependencies: &Dependencies) -> Vec<MglError> {
+  let mut visits = HashMap::new();
+  let mut errors = Vec::new();
+
+  for module in dependencies.keys() {
+    visit_module(module, dependencies, &mut visits, &mut Vec::new(), &mut errors);
+  }
+  errors
+}
+
+fn visit_module(module:       &Module,
+                dependencies: &Dependencies,
+                visits:       &mut HashMap<Module, Visit>,
+                path:         &mut Vec<Module>,
+                errors:       &mut Vec<MglError>) {
+
+  if visits.contains_key(module) {
+    return
+  }
+  visits.insert(module.clone(), Visit::InProgress);
+  path.push(module.clone());
+
+  if let Some(imports) = dependencies.get(module) {
+    for (imported_module, import) in imports {
+      match visits.get(imported_module) {
+        Some(Visit::InProgress) => {
+          let start = path.iter().position(|m| m == imported_module).unwrap();
+          let mut cycle = path[start..].to_vec();
+          cycle.push(imported_module.clone());
+          errors.push(MglError::CyclicImport { import: import.clone(), cycle });
+        }
+
+        Some(Visit::Done) => {}
+
+        None => visit_module(imported_module, dependencies, visits, path, errors)
+      }
+    }
+  }
+
+  path.pop();
+  visits.insert(module.clone(), Visit::Done);
+}
+
+
+fn rename_tree(tree: AstFileTree) -> AstFileTree {
+  match tree {
+    AstFileTree::Root(box subtree) => AstFileTree::Root(box rename_tree(subtree)),
+
+    AstFileTree::Node(directory, children) => {
+      AstFileTree::Node(directory, children.into_iter().map(rename_tree).collect())
+    }
+
+    AstFileTree::Leaf(file_name, mut top) => {
+      let mut aliases = Aliases::new();
+
+      for declaration in &top.declarations {
+        if let Declaration::Import(import) = declaration {
+          aliases.insert(import.alias.clone(), import.resource_name());
+        }
+      }
+
+      if !aliases.is_empty() {
+        for declaration in &mut top.declarations {
+          rename_declaration(declaration, &aliases);
+        }
+      }
+      AstFileTree::Leaf(file_name, top)
+    }
+  }
+}
+
+fn rename_declaration(declaration: &mut Declaration, aliases: &Aliases) {
+  match declaration {
+    Declaration::Function(function) => rename_function(function, aliases),
+    Declaration::Instance(instance) => rename_instance(instance, aliases),
+    Declaration::Constant(constant) => rename_expression(&mut constant.value, aliases),
+
+    Declaration::Enum(enumeration) => {
+      for variant in &mut enumeration.variants {
+        if let Some(value) = &mut variant.value {
+          rename_expression(value, aliases);
+        }
+      }
+    }
+
+    Declaration::Resource(resource) => {
+      rename_key_values(&mut resource.key_value_pairs, aliases);
+
+      for constant in &mut resource.constants {
+        rename_expression(&mut constant.value, aliases);
+      }
+
+      for method in &mut resource.methods {
+        rename_function(method, aliases);
+      }
+
+      for instance in &mut resource.instances {
+        rename_instance(instance, aliases);
+      }
+    }
+
+    Declaration::Module(_) | Declaration::Import(_) => {}
+  }
+}
+
+fn rename_function(function: &mut FunctionDeclaration, aliases: &Aliases) {
+  let mut aliases = aliases.clone();
+  for name in function.local_names() {
+    aliases.remove(name);
+  }
+  rename_statement(&mut function.body, &aliases);
+}
+
+fn rename_instance(instance: &mut InstanceDeclaration, aliases: &Aliases) {
+  if let Some(object) = rename_resource(&instance.object.content, aliases) {
+    instance.object = instance.object.clone().with_content(object);
+  }
+  rename_key_values(&mut instance.key_value_pairs, aliases);
+}
+
+fn rename_key_values(key_values: &mut Vec<KeyValue>, aliases: &Aliases) {
+  for key_value in key_values {
+    rename_expression(&mut key_value.value, aliases);
+  }
+}
+
+fn rename_resource(name: &ResourceName, aliases: &Aliases) -> Option<ResourceName> {
+  match name {
+    ResourceName::Name(name) => aliases.get(name).cloned(),
+    ResourceName::InModule(module, box rest) => aliases.get(module).map(|m| m.join(rest)),
+  }
+}
+
+fn rename_statement(statement: &mut IStat, aliases: &Aliases) {
+  match &mut *statement.content {
+    Statement::Return(e) | Statement::Call(e) => rename_expression(e, aliases),
+
+    Statement::Body(statements) => {
+      for statement in statements {
+        rename_statement(statement, aliases);
+      }
+    }
+
+    Statement::Break | Statement::Continue | Statement::Exit => {}
+
+    Statement::With(e, body) | Statement::While(e, body) |
+    Statement::Repeat(e, body) | Statement::DoUntil(body, e) => {
+      rename_expression(e, aliases);
+      rename_statement(body, aliases);
+    }
+
+    Statement::If(condition, then, or_else) => {
+      rename_expression(condition, aliases);
+      rename_statement(then, aliases);
+      if let Some(or_else) = or_else {
+        rename_statement(or_else, aliases);
+      }
+    }
+
+    Statement::Switch(value, cases, default) => {
+      rename_expression(value, aliases);
+      for case in cases {
+        rename_expressions(&mut case.values, aliases);
+        rename_statement(&mut case.body, aliases);
+      }
+      if let Some(default) = default {
+        rename_statement(default, aliases);
+      }
+    }
+
+    Statement::For(_, range, body) => {
+      match range {
+        ForRange::Array(array) => rename_expression(array, aliases),
+        ForRange::Integer(start, end, by) => {
+          rename_expression(start, aliases);
+          rename_expression(end, aliases);
+          if let Some(by) = by {
+            rename_expression(by, aliases);
+          }
+        }
+      }
+      rename_statement(body, aliases);
+    }
+
+    Statement::Prefix(_, e) | Statement::Postfix(e, _) => rename_expression(e, aliases),
+
+    Statement::Assignment(left, right) | Statement::CompoundAssignment(_, left, right) => {
+      rename_expression(left, aliases);
+      rename_expression(right, aliases);
+    }
+
+    Statement::Var(declarations) => {
+      for declaration in declarations {
+        if let VarDeclaration::Assignment(_, value) = declaration {
+          rename_expression(value, aliases);
+        }
+      }
+    }
+  }
+}
+
+fn rename_expression(expression: &mut IExpr, aliases: &Aliases) {
+  let renamed = match &mut *expression.content {
+    Expression::Name(name)     => aliases.get(name).cloned(),
+    Expression::Resource(name) => rename_resource(name, aliases),
+
+    Expression::Str(_) | Expression::Num(_) | Expression::Bool(_) => None,
+
+    Expression::Parentheses(e) | Expression::UnaryOp(_, e) => {
+      rename_expression(e, aliases);
+      None
+    }
+
+    Expression::BinaryOp(BinaryOp::Dot, left, right) => {
+      rename_expression(left, aliases);
+      rename_field(right, aliases);
+      None
+    }
+
+    Expression::BinaryOp(_, left, right) => {
+      rename_expression(left, aliases);
+      rename_expression(right, aliases);
+      None
+    }
+
+    Expression::TernaryOp(condition, a, b) => {
+      rename_expression(condition, aliases);
+      rename_expression(a, aliases);
+      rename_expression(b, aliases);
+      None
+    }
+
+    Expression::Call(caller, arguments) => {
+      rename_expression(caller, aliases);
+      rename_expressions(arguments, aliases);
+      None
+    }
+
+    Expression::Indexing(value, _, keys) => {
+      rename_expression(value, aliases);
+      rename_expressions(keys, aliases);
+      None
+    }
+
+    Expression::Array(values) => {
+      rename_expressions(values, aliases);
+      None
+    }
+
+    Expression::Map(entries) => {
+      for (key, value) in entries {
+        rename_expression(key, aliases);
+        rename_expression(value, aliases);
+      }
+      None
+    }
+  };
+
+  if let Some(name) = renamed {
+    *expression.content = Expression::Resource(name);
+  }
+}
+
+/// The right side of `a.b` is a field name, only its arguments and indices
+/// can refer to an alias.
+fn rename_field(field: &mut IExpr, aliases: &Aliases) {
+  match &mut *field.content {
+    Expression::Name(_) => {}
+
+    Expression::Call(caller, arguments) => {
+      rename_field(caller, aliases);
+      rename_expressions(arguments, aliases);
+    }
+
+    Expression::Indexing(value, _, keys) => {
+      rename_field(value, aliases);
+      rename_expressions(keys, aliases);
+    }
+
+    _ => rename_expression(field, aliases)
+  }
+}
+
+fn rename_expressions(expressions: &mut Vec<IExpr>, aliases: &Aliases) {
+  for expression in expressions {
+    rename_expression(expression, aliases);
+  }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Write;
+
+/// A JSON value whose objects keep the order their keys were given in,
+/// so generated files stay stable between builds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+  Null,
+  Bool(bool),
+  Num(String),
+  Str(String),
+  Array(Vec<Json>),
+  Object(Vec<(String, Json)>),
+}
+
+pub macro json_object($($key: expr => $value: expr),* $(,)?) {
+  Json::Object(vec![$((String::from($key), Json::from($value))),*])
+}
+
+impl Json {
+  pub fn array<T: Into<Json>>(values: Vec<T>) -> Json {
+    Json::Array(values.into_iter().map(Into::into).collect())
+  }
+
+  /// Indents with four spaces, like Game Maker Studio 2 does.
+  pub fn pretty(&self) -> String {
+    let mut result = String::new();
+    self.write(&mut result, Some(0));
+    result
+  }
+
+  /// Everything in a single line.
+  pub fn compact(&self) -> String {
+    let mut result = String::new();
+    self.write(&mut result, None);
+    result
+  }
+
+  fn write(&self, out: &mut String, indentation: Option<usize>) {
+    let inner = indentation.map(|i| i + 1);
+    let newline = |out: &mut String, level: Option<usize>| {
+      if let Some(level) = level {
+        out.push('\n');
+        out.push_str(&"    ".repeat(level));
+      }
+    };
+
+    match self {
+      Json::Null      => out.push_str("null"),
+      Json::Bool(b)   => out.push_str(if *b { "true" } else { "false" }),
+      Json::Num(n)    => out.push_str(n),
+      Json::Str(s)    => out.push_str(&quote(s)),
+
+      Json::Array(values) if values.is_empty() => out.push_str("[]"),
+      Json::Object(pairs) if pairs.is_empty()  => out.push_str("{}"),
+
+      Json::Array(values) => {
+        out.push('[');
+        for (i, value) in values.iter().enumerate() {
+          if i > 0 { out.push(','); }
+          newline(out, inner);
+          value.write(out, inner);
+        }
+        newline(out, indentation);
+        out.push(']');
+      }
+
+      Json::Object(pairs) => {
+        out.push('{');
+        for (i, (key, value)) in pairs.iter().enumerate() {
+          if i > 0 { out.push(','); }
+          newline(out, inner);
+          out.push_str(&quote(key));
+          out.push(':');
+          if indentation.is_some() { out.push(' '); }
+          value.write(out, inner);
+        }
+        newline(out, indentation);
+        out.push('}');
+      }
+    }
+  }
+}
+
+fn quote(s: &str) -> String {
+  let mut result = String::from("\"");
+
+  for c in s.chars() {
+    match c {
+      '"'  => result.push_str("\\\""),
+      '\\' => result.push_str("\\\\"),
+      '\n' => result.push_str("\\n"),
+      '\r' => result.push_str("\\r"),
+      '\t' => result.push_str("\\t"),
+      c if (c as u32) < 0x20 => { write!(result, "\\u{:04x}", c as u32).unwrap(); }
+      c => result.push(c),
+    }
+  }
+  result.push('"');
+  result
+}
+
+macro implement_json_from_number($($T: ty),*) {
+  $(
+    impl From<$T> for Json {
+      fn from(n: $T) -> Json {
+        Json::Num(n.to_string())
+      }
+    }
+  )*
+}
+
+implement_json_from_number!(u8, u32, u64, i32, i64, f32, f64, usize);
+
+impl From<bool> for Json {
+  fn from(b: bool) -> Json {
+    Json::Bool(b)
+  }
+}
+
+impl From<&str> for Json {
+  fn from(s: &str) -> Json {
+    Json::Str(String::from(s))
+  }
+}
+
+impl From<String> for Json {
+  fn from(s: String) -> Json {
+    Json::Str(s)
+  }
+}
+
+impl From<&String> for Json {
+  fn from(s: &String) -> Json {
+    Json::Str(s.clone())
+  }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+  fn from(value: Option<T>) -> Json {
+    value.map(Into::into).unwrap_or(Json::Null)
+  }
+}
+
+impl From<Vec<Json>> for Json {
+  fn from(values: Vec<Json>) -> Json {
+    Json::Array(values)
+  }
+}
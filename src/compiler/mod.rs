@@ -1,4 +1,12 @@
 pub mod file_reader;
 pub mod resource_tree;
+pub mod imports;
+pub mod control_flow;
+pub mod constants;
 pub mod script;
-
+pub mod xml;
+pub mod export;
+pub mod gms1;
+pub mod json;
+pub mod guid;
+pub mod gms2;
@@ -2,10 +2,83 @@ use std::path::PathBuf;
 
 use crate::error::*;
 use crate::compiler::file_reader::*;
+use crate::compiler::imports::resolve_imports;
+use crate::compiler::control_flow::check_control_flow;
+use crate::compiler::constants::{Constants, evaluate_constants};
 use crate::resources::project::{Project, Module};
+use crate::manifest::ProjectManifest;
 
 pub fn read_resource_tree(project_file: Option<PathBuf>) -> TopResult<Project> {
-  let file_tree = AstFileTree::from_project(project_file)?;
-  Project::from_ast_file_tree(file_tree, Module::new())
+  check_file_tree(AstFileTree::from_project(project_file)?)
 }
 
+
+pub fn read_manifest_resource_tree(manifest: &ProjectManifest) -> TopResult<Project> {
+  check_file_tree(AstFileTree::from_manifest(manifest))
+}
+
+/// Syntax errors are reported along with whatever the rest of the files have.
+fn check_file_tree((file_tree, mut errors): Recovered<AstFileTree>) -> TopResult<Project> {
+  let file_tree = match resolve_imports(file_tree) {
+    Ok(file_tree) => file_tree,
+
+    Err(mut e) => {
+      errors.append(&mut e);
+      return Err(errors)
+    }
+  };
+
+  if let Err(mut e) = check_control_flow(&file_tree) {
+    errors.append(&mut e);
+  }
+
+  let constants = match evaluate_constants(&file_tree) {
+    Ok(constants) => constants,
+
+    Err(mut e) => {
+      errors.append(&mut e);
+      Constants::new()
+    }
+  };
+
+  match link_project(file_tree, &constants) {
+    Ok(project) if errors.is_empty() => Ok(project),
+    Ok(_) => Err(errors),
+
+    Err(mut e) => {
+      errors.append(&mut e);
+      Err(errors)
+    }
+  }
+}
+
+fn link_project(file_tree: AstFileTree, constants: &Constants) -> TopResult<Project> {
+  let mut project = Project::from_ast_file_tree(file_tree, Module::new())?;
+  let mut errors  = Vec::new();
+
+  project.attach_constants(constants);
+
+  if let Err(mut e) = project.link_instances() {
+    errors.append(&mut e);
+  }
+
+  if let Err(mut e) = project.check_inheritance() {
+    errors.append(&mut e);
+  }
+
+  if let Err(mut e) = project.link_methods() {
+    errors.append(&mut e);
+  }
+
+  project.link_wrappers();
+
+  if let Err(mut e) = project.validate_references() {
+    errors.append(&mut e);
+  }
+
+  if errors.is_empty() {
+    Ok(project)
+  } else {
+    Err(errors)
+  }
+}
@@ -1,45 +1,79 @@
 use crate::ast::*;
+use crate::error::*;
+use crate::compiler::export::asset_name;
 use crate::resources::script::*;
 
-struct StatementBuilder {
+/// What the version of GML being written can express directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GmlFeatures {
+  /// `a ? b : c`, added in Game Maker Studio 2
+  pub ternary_operator: bool,
+  /// `[a, b, c]`, added in Game Maker Studio 2.3
+  pub array_literals: bool,
+  /// `instance_create_depth`, Game Maker Studio 2 has no `instance_create`
+  pub instance_depth: bool,
+}
+
+impl GmlFeatures {
+  /// Game Maker: Studio 1.4, which is what the GMS1 exporter writes.
+  pub const LEGACY: GmlFeatures = GmlFeatures { ternary_operator: false, array_literals: false, instance_depth: false };
+  /// Game Maker Studio 2 up to 2.2, which is what the GMS2 exporter writes.
+  pub const GMS2: GmlFeatures = GmlFeatures { ternary_operator: true, instance_depth: true, ..GmlFeatures::LEGACY };
+}
+
+struct StatementBuilder<'a> {
   root: bool,
   result: String,
   indentation: usize,
+  features: GmlFeatures,
+  script: &'a Script,
+  temporaries: usize,
+  /// The condition of the `do ... until` a `continue` is in, when it needs statements of its own.
+  until: Option<IExpr>,
+}
+
+pub fn build_script(s: Script) -> Result<String> {
+  build_script_for(s, GmlFeatures::LEGACY)
 }
 
-pub fn build_script(s: Script) -> String {
+pub fn build_script_for(s: Script, features: GmlFeatures) -> Result<String> {
   let source = &s.source;
 
-  let mut builder = StatementBuilder::new(true, 0);
+  let mut builder = StatementBuilder::new(true, 0, features, &s);
   builder.argument_vars(&source.args);
-  builder.build_statement(source.body.as_ref());
+  builder.build_statement(source.body.as_ref())?;
 
-  format!("{}", builder.result)
+  Ok(builder.result)
 }
 
-fn build_expression<T: AsRef<Expression>>(e: &T) -> String {
+fn build_expression(e: &IExpr) -> Result<String> {
   use crate::ast::BinaryOp::Dot;
   use Expression::*;
   let ex = build_expression;
 
-  match &e.as_ref() {
+  let built = match &e.as_ref() {
     &Str(string)         => format!("\"{}\"", string),
     &Num(number)         => number.clone(),
     &Bool(true)          => String::from("true"),
     &Bool(false)         => String::from("false"),
     &Name(name)          => name.clone(),
     &Resource(name)      => build_resource_name(&name),
-    &Parentheses(e)      => format!("({})", build_expression(&e)),
-    &UnaryOp(op, e)      => format!("{}{}", op.as_str(), build_expression(&e)),
-    &BinaryOp(Dot, a, b) => format!("{}.{}", ex(&a), ex(&b)),
-    &BinaryOp(op, a, b)  => format!("{} {} {}", ex(&a), op.as_str(), ex(&b)),
-    &Call(f, ref args)   => build_call(&f, args),
-    &Indexing(v, a, k)   => build_indexing(&v, *a, &k),
-    &TernaryOp(_,_,_)    => unreachable!()
-  }
+    &Parentheses(e)      => format!("({})", build_expression(e)?),
+    &UnaryOp(op, e)      => format!("{}{}", op.as_str(), build_expression(e)?),
+    &BinaryOp(Dot, a, b) => format!("{}.{}", ex(a)?, ex(b)?),
+    &BinaryOp(op, a, b)  => format!("{} {} {}", ex(a)?, op.as_str(), ex(b)?),
+    &Call(f, ref args)   => build_call(f, args)?,
+    &Indexing(v, a, k)   => build_indexing(v, *a, &k)?,
+    &TernaryOp(c, a, b)  => format!("{} ? {} : {}", ex(c)?, ex(a)?, ex(b)?),
+    &Array(values)       => format!("[{}]", join_arguments(values)?),
+
+    // GML has no map literal, `lower_expression` fills a `ds_map` instead
+    &Map(_) => return MglError::convert_expression(e.clone(), "GML expression"),
+  };
+  Ok(built)
 }
 
-fn build_resource_name(name: &ResourceName) -> String {
+pub fn build_resource_name(name: &ResourceName) -> String {
   match &name {
     &ResourceName::Name(name) => name.clone(),
     &ResourceName::InModule(m, n) => {
@@ -49,60 +83,75 @@ fn build_resource_name(name: &ResourceName) -> String {
 }
 
 
-fn join_arguments<T: AsRef<Expression>>(v: &Vec<T>) -> String {
-  v.iter().map(|e| build_expression(e.as_ref())).collect::<Vec<_>>().join(", ")
+fn join_arguments(v: &Vec<IExpr>) -> Result<String> {
+  Ok(v.iter().map(build_expression).collect::<Result<Vec<_>>>()?.join(", "))
 }
 
-fn build_call<T,U>(caller: &T, args: &Vec<U>) -> String
-  where T: AsRef<Expression>,
-        U: AsRef<Expression> {
-
-  let mut result = build_expression(caller);
+fn build_call(caller: &IExpr, args: &Vec<IExpr>) -> Result<String> {
+  let mut result = build_expression(caller)?;
 
   result.push('(');
-  result.push_str(&join_arguments(args));
+  result.push_str(&join_arguments(args)?);
   result.push(')');
 
-  result
+  Ok(result)
 }
 
-fn build_indexing<T,U>(v: &T, op: Accessor, keys: &Vec<U>) -> String
-  where T: AsRef<Expression>,
-        U: AsRef<Expression> {
-
-  let mut result = build_expression(v);
+fn build_indexing(v: &IExpr, op: Accessor, keys: &Vec<IExpr>) -> Result<String> {
+  let mut result = build_expression(v)?;
 
   result.push('[');
   result.push_str(op.as_str());
-  result.push_str(&join_arguments(keys));
+  result.push_str(&join_arguments(keys)?);
   result.push(']');
 
-  result
+  Ok(result)
 }
 
 
-fn build_for_range(var: &str, f: &ForRange) -> String {
-  match f {
-    ForRange::Integer(from, to, Some(by)) => {
-      let from = build_expression(from);
-      let to   = build_expression(to);
-      let by   = build_expression(by);
-      format!("var {v} = {}; {v} != {}; {v} += {}", from, to, by, v=var)
+/// Whether a `by` step is known to be positive or negative without running it.
+fn step_sign<T: AsRef<Expression>>(by: &T) -> Option<bool> {
+  match by.as_ref() {
+    Expression::Num(n) if n.parse::<f64>().map(|n| n > 0.0).unwrap_or(false) => Some(true),
+
+    Expression::UnaryOp(UnaryOp::Neg, e) => {
+      match e.as_ref() {
+        Expression::Num(n) if n.parse::<f64>().map(|n| n > 0.0).unwrap_or(false) => Some(false),
+        _ => None
+      }
     }
-    _ => unreachable!()
+    _ => None
   }
 }
 
 
-impl StatementBuilder {
-  fn new(root: bool, indentation: usize) -> Self {
+impl<'a> StatementBuilder<'a> {
+  fn new(root: bool, indentation: usize, features: GmlFeatures, script: &'a Script) -> Self {
     StatementBuilder {
       root,
       result: String::new(),
-      indentation
+      indentation,
+      features,
+      script,
+      temporaries: 0,
+      until: None,
+    }
+  }
+
+  /// Temporaries are numbered across the whole script, so children share the count.
+  fn child(&self, indentation: usize) -> Self {
+    StatementBuilder {
+      temporaries: self.temporaries,
+      until: self.until.clone(),
+      ..StatementBuilder::new(false, indentation, self.features, self.script)
     }
   }
 
+  fn append(&mut self, child: StatementBuilder<'a>) {
+    self.temporaries = self.temporaries.max(child.temporaries);
+    self.result.push_str(&child.result);
+  }
+
   fn add(&mut self, line: &str) {
     self.result.push_str(&format!("{}{}", " ".repeat(self.indentation), line));
   }
@@ -115,56 +164,227 @@ impl StatementBuilder {
     }
   }
 
-  fn build_statement<T: AsRef<Statement>>(&mut self, statement: T) {
+  fn build_statement<T: AsRef<Statement>>(&mut self, statement: T) -> Result<()> {
     match &statement.as_ref() {
       &Statement::Return(expr) => {
-        self.add(&format!("return {};\n", build_expression(&expr)));
+        let expr = self.lower_expression(expr)?;
+        self.add(&format!("return {};\n", build_expression(&expr)?));
+      }
+
+      &Statement::Break    => self.add("break;\n"),
+      &Statement::Continue => {
+        if let Some(until) = self.until.clone() {
+          self.until_check(&until, self.indentation)?;
+        }
+        self.add("continue;\n");
       }
+      &Statement::Exit     => self.add("exit;\n"),
 
       &Statement::Call(expr) => {
-        self.add(&format!("{};\n", build_expression(&expr)));
+        let script = self.script;
+
+        match method_call(expr) {
+          Some(call) if call.instance == "other" && script.methods.contains_key(call.method) => {
+            self.call_with_other(&script.methods[call.method], call.arguments, None)?;
+          }
+
+          _ => {
+            let expr = self.lower_expression(expr)?;
+            self.add(&format!("{};\n", build_expression(&expr)?));
+          }
+        }
       }
 
       &Statement::Body(statements) => {
         let indentation = if self.root {0} else {self.indentation + 4};
-        let mut builder = StatementBuilder::new(false, indentation);
+        let mut builder = self.child(indentation);
         for statement in statements {
-          builder.build_statement(statement);
+          builder.build_statement(statement)?;
         }
-        self.add(&builder.result);
+        self.append(builder);
       }
 
       &Statement::With(with, body) => {
-        self.add(&format!("with {} {{\n", build_expression(&with)));
-        self.build_statement(body);
+        let with = self.lower_expression(with)?;
+        self.add(&format!("with {} {{\n", build_expression(&with)?));
+        self.build_loop_body(body, None)?;
         self.add("}\n");
       }
 
       &Statement::If(cond, then, or_else) => {
-        self.add(&format!("if {} {{\n", build_expression(&cond)));
-        self.build_statement(then);
+        let cond = self.lower_expression(cond)?;
+        self.add(&format!("if {} {{\n", build_expression(&cond)?));
+        self.build_statement(then)?;
 
         if let Some(or_else) = or_else {
           self.add("} else {\n");
-          self.build_statement(or_else);
+          self.build_statement(or_else)?;
         }
         self.add("}\n");
       }
 
       &Statement::While(cond, body) => {
-        self.add(&format!("while {} {{\n", build_expression(&cond)));
-        self.build_statement(body);
+        let mut condition = self.child(self.indentation + 4);
+        let cond = condition.lower_expression(cond)?;
+
+        if condition.result.is_empty() {
+          self.add(&format!("while {} {{\n", build_expression(&cond)?));
+
+        } else {
+          // the condition needs statements of its own, which must run on every iteration
+          condition.add(&format!("if !({}) {{\n", build_expression(&cond)?));
+          condition.add("    break;\n");
+          condition.add("}\n");
+
+          self.add("while true {\n");
+          self.append(condition);
+        }
+        self.build_loop_body(body, None)?;
         self.add("}\n");
       }
 
-      &Statement::For(var, range, body) => {
-        self.add(&format!("for ({}) {{\n", build_for_range(&var, &range)));
-        self.build_statement(body);
+      &Statement::Repeat(times, body) => {
+        let times = self.lower_expression(times)?;
+        self.add(&format!("repeat {} {{\n", build_expression(&times)?));
+        self.build_loop_body(body, None)?;
+        self.add("}\n");
+      }
+
+      &Statement::DoUntil(body, cond) => {
+        let mut condition = self.child(self.indentation + 4);
+        let lowered = condition.lower_expression(cond)?;
+
+        if condition.result.is_empty() {
+          self.add("do {\n");
+          self.build_loop_body(body, None)?;
+          self.add(&format!("}} until {};\n", build_expression(&lowered)?));
+
+        } else {
+          // the statements the condition needs have to run before every `continue` too
+          self.add("while true {\n");
+          self.build_loop_body(body, Some(cond.clone()))?;
+          self.until_check(cond, self.indentation + 4)?;
+          self.add("}\n");
+        }
+      }
+
+      &Statement::Switch(value, cases, default) => {
+        let value = self.lower_expression(value)?;
+        let cases = cases.iter().map(|case| {
+          Ok((case.values.iter().map(|v| self.lower_expression(v)).collect::<Result<Vec<_>>>()?, &case.body))
+        }).collect::<Result<Vec<_>>>()?;
+
+        self.add(&format!("switch {} {{\n", build_expression(&value)?));
+
+        for (values, body) in cases {
+          let labels = values.iter().map(|v| Ok(format!("case {}:\n", build_expression(v)?)));
+          self.build_switch_branch(labels.collect::<Result<_>>()?, body)?;
+        }
+
+        if let Some(default) = default {
+          self.build_switch_branch(vec![String::from("default:\n")], default)?;
+        }
+        self.add("}\n");
+      }
+
+      &Statement::For(var, ForRange::Array(array), body) => {
+        let array   = self.lower_expression(array)?;
+        let counter = self.temporaries;
+        let values  = format!("__mgl_array{}", counter);
+        let index   = format!("__mgl_index{}", counter);
+        self.temporaries += 1;
+
+        self.add(&format!("var {} = {};\n", values, build_expression(&array)?));
+        self.add(&format!("for (var {i} = 0; {i} < array_length_1d({a}); {i} += 1) {{\n",
+                          i=index, a=values));
+
+        // the loop variable is declared at the start of the body
+        let element = Expression::indexing(
+          IExpr::new(Expression::name(&values)),
+          Accessor::None,
+          &[IExpr::new(Expression::name(&index))],
+        );
+        let declaration = IStat::new(Statement::var(&[
+          VarDeclaration::Assignment(var.clone(), IExpr::new(element))
+        ]));
+
+        let mut statements = vec![declaration];
+        match body.as_ref() {
+          Statement::Body(body) => statements.extend(body.iter().cloned()),
+          _ => statements.push(body.clone()),
+        }
+        self.build_loop_body(Statement::body(&statements), None)?;
+        self.add("}\n");
+      }
+
+      &Statement::For(var, ForRange::Integer(from, to, by), body) => {
+        let from = self.lower_expression(from)?;
+
+        // the bound is checked on every iteration, so are the statements it needs
+        let mut bound = self.child(self.indentation + 4);
+        let to   = bound.lower_expression(to)?;
+        let to_s = build_expression(&to)?;
+        self.temporaries = bound.temporaries;
+
+        let (step, condition) = match by {
+          None => (String::from("1"), format!("{} < {}", var, to_s)),
+
+          Some(by) => {
+            let by = self.lower_expression(by)?;
+
+            match step_sign(&by) {
+              Some(true)  => (build_expression(&by)?, format!("{} < {}", var, to_s)),
+              Some(false) => (build_expression(&by)?, format!("{} > {}", var, to_s)),
+
+              // the direction is only known once the step is, a step of 0 doesn't loop
+              None => {
+                let step = format!("__mgl_step{}", self.temporaries);
+                self.temporaries += 1;
+                self.add(&format!("var {} = {};\n", step, build_expression(&by)?));
+
+                let condition = format!("({s} > 0 && {v} < {t}) || ({s} < 0 && {v} > {t})",
+                                        s=step, v=var, t=to_s);
+                (step, condition)
+              }
+            }
+          }
+        };
+
+        if bound.result.is_empty() {
+          self.add(&format!("for (var {v} = {}; {}; {v} += {}) {{\n",
+                            build_expression(&from)?, condition, step, v=var));
+        } else {
+          bound.add(&format!("if !({}) {{\n", condition));
+          bound.add("    break;\n");
+          bound.add("}\n");
+
+          self.add(&format!("for (var {v} = {}; true; {v} += {}) {{\n", build_expression(&from)?, step, v=var));
+          self.append(bound);
+        }
+        self.build_loop_body(body, None)?;
         self.add("}\n");
       }
 
       Statement::Assignment(left, right) => {
-        self.add(&format!("{} = {};\n", build_expression(left), build_expression(right)));
+        let left  = self.lower_target(left)?;
+        let right = self.lower_expression(right)?;
+        self.add(&format!("{} = {};\n", build_expression(&left)?, build_expression(&right)?));
+      }
+
+      Statement::CompoundAssignment(op, left, right) => {
+        let left  = self.lower_target(left)?;
+        let right = self.lower_expression(right)?;
+        self.add(&format!("{} {}= {};\n", build_expression(&left)?, op.as_str(), build_expression(&right)?));
+      }
+
+      Statement::Prefix(op, e) => {
+        let e = self.lower_target(e)?;
+        self.add(&format!("{}{};\n", op.as_str(), build_expression(&e)?));
+      }
+
+      Statement::Postfix(e, op) => {
+        let e = self.lower_target(e)?;
+        self.add(&format!("{}{};\n", build_expression(&e)?, op.as_str()));
       }
 
       Statement::Var(vars) => {
@@ -173,7 +393,8 @@ impl StatementBuilder {
         for var in vars {
           match var {
             VarDeclaration::Assignment(var, expr) => {
-              parts.push(format!("{} = {}", var, build_expression(expr)));
+              let expr = self.lower_expression(expr)?;
+              parts.push(format!("{} = {}", var, build_expression(&expr)?));
             }
             VarDeclaration::Name(var) => {
               parts.push(var.clone())
@@ -183,6 +404,313 @@ impl StatementBuilder {
         self.add(&format!("var {};\n", parts.join(", ")));
       }
     }
+    Ok(())
   }
-}
 
+  /// A `continue` inside a loop belongs to it rather than to the loops around it.
+  fn build_loop_body<T: AsRef<Statement>>(&mut self, body: T, until: Option<IExpr>) -> Result<()> {
+    let outer = std::mem::replace(&mut self.until, until);
+    let built = self.build_statement(body);
+    self.until = outer;
+    built
+  }
+
+  /// Leaves a `do ... until` lowered into a `while true` once its condition holds.
+  fn until_check(&mut self, until: &IExpr, indentation: usize) -> Result<()> {
+    let mut check = self.child(indentation);
+    let until = check.lower_expression(until)?;
+    check.add(&format!("if {} {{\n", build_expression(&until)?));
+    check.add("    break;\n");
+    check.add("}\n");
+    self.append(check);
+    Ok(())
+  }
+
+  /// Cases don't fall through in MGL, so every branch ends with a `break`.
+  fn build_switch_branch(&mut self, labels: Vec<String>, body: &IStat) -> Result<()> {
+    let mut branch = self.child(self.indentation + 4);
+
+    for label in labels {
+      branch.add(&label);
+    }
+    branch.build_statement(body)?;
+    branch.add("    break;\n");
+    self.append(branch);
+    Ok(())
+  }
+
+  /// Replaces constants by their values, method and `super()` calls by calls
+  /// to their scripts, and what the version of GML being written can't express by
+  /// temporaries, filled before the statement the expression is in. Without
+  /// the ternary operator `c ? a : b` is computed with an `if`, array literals
+  /// become `array_create` and map literals `ds_map_create`.
+  fn lower_expression(&mut self, expression: &IExpr) -> Result<IExpr> {
+    use crate::ast::BinaryOp::{Dot, And, Or};
+    use Expression::*;
+
+    let script = self.script;
+    if let (Some((_, arguments)), Some(target)) = (super_call(expression), &script.super_call) {
+      return self.lower_super_call(expression, arguments, target)
+    }
+
+    if let Some(call) = method_call(expression) {
+      if let Some(method) = script.methods.get(call.method) {
+        return self.lower_method_call(expression, &call, method)
+      }
+    }
+
+    let lowered = match expression.content_clone() {
+      Name(name) => self.constant(&ResourceName::Name(name.clone())).unwrap_or(Name(name)),
+      Resource(name) => self.constant(&name).unwrap_or(Resource(name)),
+
+      TernaryOp(condition, a, b) if !self.features.ternary_operator => {
+        self.hoist_ternary(&condition, &a, &b)?
+      }
+
+      TernaryOp(condition, a, b) => {
+        let condition = self.lower_expression(&condition)?;
+        let a = self.lower_expression(&a)?;
+        let b = self.lower_expression(&b)?;
+        TernaryOp(condition, a, b)
+      }
+
+      Array(values) if !self.features.array_literals => self.hoist_array(&values)?,
+      Array(values) => Array(values.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?),
+      Map(entries)  => self.hoist_map(&entries)?,
+
+      Parentheses(e) => Parentheses(self.lower_expression(&e)?),
+      UnaryOp(op, e) => UnaryOp(op, self.lower_expression(&e)?),
+
+      BinaryOp(Dot, a, b) => {
+        let a = self.lower_expression(&a)?;
+        let b = self.lower_field(&b)?;
+        BinaryOp(Dot, a, b)
+      }
+
+      BinaryOp(op, a, b) if op == And || op == Or => self.lower_logical(op, &a, &b)?,
+
+      BinaryOp(op, a, b) => {
+        let a = self.lower_expression(&a)?;
+        let b = self.lower_expression(&b)?;
+        BinaryOp(op, a, b)
+      }
+
+      Call(caller, arguments) => {
+        let caller = self.lower_expression(&caller)?;
+        let arguments = arguments.iter().map(|e| self.lower_expression(e)).collect::<Result<Vec<_>>>()?;
+
+        match (caller.as_ref(), &arguments[..]) {
+          (Name(function), [x, y, object]) if function == "instance_create" && self.features.instance_depth => {
+            self.instance_create_depth(&caller, x, y, object)?
+          }
+          _ => Call(caller, arguments)
+        }
+      }
+
+      Indexing(value, accessor, keys) => {
+        let value = self.lower_expression(&value)?;
+        let keys  = keys.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?;
+        Indexing(value, accessor, keys)
+      }
+
+      e => e
+    };
+    Ok(expression.clone().with_content(lowered))
+  }
+
+  /// The right side of `a && b` and `a || b` only runs when the left side
+  /// doesn't decide the result, and neither do the statements it needs.
+  fn lower_logical(&mut self, op: BinaryOp, a: &IExpr, b: &IExpr) -> Result<Expression> {
+    let a = self.lower_expression(a)?;
+    let mut right = self.child(self.indentation + 4);
+    let b = right.lower_expression(b)?;
+    self.temporaries = right.temporaries;
+
+    if right.result.is_empty() {
+      return Ok(Expression::BinaryOp(op, a, b))
+    }
+
+    let (a, b) = (build_expression(&a)?, build_expression(&b)?);
+    let (temporary, initial, test_a, test_b, value) = match op {
+      BinaryOp::And => (self.new_temporary("and"), "false", a, b, "true"),
+      _             => (self.new_temporary("or"), "true", format!("!({})", a), format!("!({})", b), "false"),
+    };
+
+    self.add(&format!("var {} = {};\n", temporary, initial));
+    self.add(&format!("if {} {{\n", test_a));
+    right.add(&format!("if {} {{\n", test_b));
+    right.add(&format!("    {} = {};\n", temporary, value));
+    right.add("}\n");
+    self.append(right);
+    self.add("}\n");
+
+    Ok(Expression::Name(temporary))
+  }
+
+  /// A method runs in the instance calling it, so `self.f()` calls the
+  /// method's script directly and `other.f()` calls it inside a `with`.
+  fn lower_method_call(&mut self, expression: &IExpr, call: &MethodCall, method: &ResourceName) -> Result<IExpr> {
+    if call.instance == "self" {
+      let arguments = call.arguments.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?;
+      let method    = IExpr::with_location_of(Expression::Name(asset_name(method)), call.location);
+      return Ok(expression.clone().with_content(Expression::Call(method, arguments)))
+    }
+
+    let result = self.new_temporary("result");
+    self.add(&format!("var {};\n", result));
+    self.call_with_other(method, call.arguments, Some(&result))?;
+    Ok(expression.clone().with_content(Expression::Name(result)))
+  }
+
+  fn lower_super_call(&mut self, expression: &IExpr, arguments: &[IExpr], target: &SuperCall) -> Result<IExpr> {
+    let (function, arguments) = match target {
+      SuperCall::Event => (String::from("event_inherited"), Vec::new()),
+      SuperCall::Method(method) => (asset_name(method), arguments.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?),
+    };
+
+    let function = IExpr::with_location_of(Expression::Name(function), expression);
+    Ok(expression.clone().with_content(Expression::Call(function, arguments)))
+  }
+
+  fn call_with_other(&mut self, method: &ResourceName, arguments: &[IExpr], result: Option<&str>) -> Result<()> {
+    // inside the `with` names are looked up in the other instance, so arguments are computed before it
+    let arguments = arguments.iter().map(|argument| {
+      let argument = self.lower_expression(argument)?;
+
+      match argument.as_ref() {
+        Expression::Num(_) | Expression::Str(_) | Expression::Bool(_) => build_expression(&argument),
+
+        _ => {
+          let temporary = self.new_temporary("argument");
+          self.add(&format!("var {} = {};\n", temporary, build_expression(&argument)?));
+          Ok(temporary)
+        }
+      }
+    }).collect::<Result<Vec<_>>>()?;
+
+    let assignment = result.map(|result| format!("{} = ", result)).unwrap_or_default();
+
+    self.add("with other {\n");
+    self.add(&format!("    {}{}({});\n", assignment, asset_name(method), arguments.join(", ")));
+    self.add("}\n");
+    Ok(())
+  }
+
+  /// The right side of `a.b` is a field name, it's never a constant.
+  fn lower_field(&mut self, field: &IExpr) -> Result<IExpr> {
+    use Expression::*;
+
+    let lowered = match field.content_clone() {
+      Name(name) => Name(name),
+
+      Call(caller, arguments) => {
+        let caller = self.lower_field(&caller)?;
+        let arguments = arguments.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?;
+        Call(caller, arguments)
+      }
+
+      Indexing(value, accessor, keys) => {
+        let value = self.lower_field(&value)?;
+        let keys  = keys.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?;
+        Indexing(value, accessor, keys)
+      }
+
+      _ => return self.lower_expression(field)
+    };
+    Ok(field.clone().with_content(lowered))
+  }
+
+  /// `instance_create(x, y, o)` becomes `instance_create_depth(x, y, object_get_depth(o), o)`,
+  /// keeping the depth `instance_create` gives to instances of `o`.
+  fn instance_create_depth(&mut self, caller: &IExpr, x: &IExpr, y: &IExpr, object: &IExpr) -> Result<Expression> {
+    let object = match object.as_ref() {
+      Expression::Name(_) | Expression::Resource(_) => object.clone(),
+
+      // the object is needed twice, so it's only computed once
+      _ => {
+        let temporary = self.new_temporary("object");
+        self.add(&format!("var {} = {};\n", temporary, build_expression(object)?));
+        object.clone().with_content(Expression::name(&temporary))
+      }
+    };
+
+    let name  = |name: &str| caller.clone().with_content(Expression::name(name));
+    let depth = object.clone().with_content(Expression::call(name("object_get_depth"), std::slice::from_ref(&object)));
+    Ok(Expression::call(name("instance_create_depth"), &[x.clone(), y.clone(), depth, object]))
+  }
+
+  /// What is assigned to is a variable, even when a constant has its name.
+  fn lower_target(&mut self, target: &IExpr) -> Result<IExpr> {
+    use Expression::*;
+
+    let lowered = match target.content_clone() {
+      Name(name) => Name(name),
+
+      Indexing(value, accessor, keys) => {
+        let value = self.lower_target(&value)?;
+        let keys  = keys.iter().map(|e| self.lower_expression(e)).collect::<Result<_>>()?;
+        Indexing(value, accessor, keys)
+      }
+
+      _ => return self.lower_expression(target)
+    };
+    Ok(target.clone().with_content(lowered))
+  }
+
+  fn constant(&self, name: &ResourceName) -> Option<Expression> {
+    self.script.constants.get(name).map(IExpr::content_clone)
+  }
+
+  fn new_temporary(&mut self, kind: &str) -> String {
+    let temporary = format!("__mgl_{}{}", kind, self.temporaries);
+    self.temporaries += 1;
+    temporary
+  }
+
+  fn hoist_array(&mut self, values: &[IExpr]) -> Result<Expression> {
+    let values    = values.iter().map(|e| self.lower_expression(e)).collect::<Result<Vec<_>>>()?;
+    let temporary = self.new_temporary("array");
+
+    self.add(&format!("var {} = array_create({});\n", temporary, values.len()));
+    for (index, value) in values.iter().enumerate() {
+      self.add(&format!("{}[{}] = {};\n", temporary, index, build_expression(value)?));
+    }
+    Ok(Expression::Name(temporary))
+  }
+
+  fn hoist_map(&mut self, entries: &[(IExpr, IExpr)]) -> Result<Expression> {
+    let entries = entries.iter().map(|(key, value)| {
+      Ok((self.lower_expression(key)?, self.lower_expression(value)?))
+    }).collect::<Result<Vec<_>>>()?;
+    let temporary = self.new_temporary("map");
+
+    self.add(&format!("var {} = ds_map_create();\n", temporary));
+    for (key, value) in &entries {
+      self.add(&format!("ds_map_add({}, {}, {});\n",
+                        temporary, build_expression(key)?, build_expression(value)?));
+    }
+    Ok(Expression::Name(temporary))
+  }
+
+  fn hoist_ternary(&mut self, condition: &IExpr, a: &IExpr, b: &IExpr) -> Result<Expression> {
+    let condition = self.lower_expression(condition)?;
+    let temporary = self.new_temporary("ternary");
+
+    self.add(&format!("var {};\n", temporary));
+    self.add(&format!("if {} {{\n", build_expression(&condition)?));
+    self.assign_branch(&temporary, a)?;
+    self.add("} else {\n");
+    self.assign_branch(&temporary, b)?;
+    self.add("}\n");
+
+    Ok(Expression::Name(temporary))
+  }
+
+  fn assign_branch(&mut self, temporary: &str, value: &IExpr) -> Result<()> {
+    let mut branch = self.child(self.indentation + 4);
+    let value = branch.lower_expression(value)?;
+    branch.add(&format!("{} = {};\n", temporary, build_expression(&value)?));
+    self.append(branch);
+    Ok(())
+  }
+}
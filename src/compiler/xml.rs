@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+pub struct XmlBuilder {
+  result: String,
+  indentation: usize,
+}
+
+pub type Attributes<'a> = [(&'a str, String)];
+
+impl XmlBuilder {
+  pub fn new() -> Self {
+    XmlBuilder {
+      result: String::new(),
+      indentation: 0,
+    }
+  }
+
+  pub fn document(comment: &str) -> Self {
+    let mut builder = XmlBuilder::new();
+    builder.add(&format!("<!--{}-->", comment));
+    builder
+  }
+
+  fn add(&mut self, line: &str) {
+    self.result.push_str(&format!("{}{}\n", "  ".repeat(self.indentation), line));
+  }
+
+  pub fn open(&mut self, tag: &str) {
+    self.open_with(tag, &[]);
+  }
+
+  pub fn open_with(&mut self, tag: &str, attributes: &Attributes) {
+    self.add(&format!("<{}{}>", tag, build_attributes(attributes)));
+    self.indentation += 1;
+  }
+
+  pub fn close(&mut self, tag: &str) {
+    self.indentation -= 1;
+    self.add(&format!("</{}>", tag));
+  }
+
+  pub fn leaf<T: Display>(&mut self, tag: &str, value: T) {
+    self.leaf_with(tag, &[], value);
+  }
+
+  pub fn leaf_with<T: Display>(&mut self, tag: &str, attributes: &Attributes, value: T) {
+    let value = escape(&value.to_string());
+
+    if value.is_empty() {
+      self.add(&format!("<{}{}></{}>", tag, build_attributes(attributes), tag));
+    } else {
+      self.add(&format!("<{}{}>{}</{}>", tag, build_attributes(attributes), value, tag));
+    }
+  }
+
+  pub fn empty(&mut self, tag: &str) {
+    self.empty_with(tag, &[]);
+  }
+
+  pub fn empty_with(&mut self, tag: &str, attributes: &Attributes) {
+    self.add(&format!("<{}{}/>", tag, build_attributes(attributes)));
+  }
+
+  pub fn finish(self) -> String {
+    self.result
+  }
+}
+
+fn build_attributes(attributes: &Attributes) -> String {
+  attributes.iter()
+            .map(|(name, value)| format!(" {}=\"{}\"", name, escape(value)))
+            .collect::<Vec<_>>()
+            .join("")
+}
+
+pub fn escape(s: &str) -> String {
+  s.replace("&", "&amp;")
+   .replace("<", "&lt;")
+   .replace(">", "&gt;")
+   .replace("\"", "&quot;")
+}
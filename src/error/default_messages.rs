@@ -6,6 +6,21 @@ impl ErrorMessageProvider for DefaultErrorMessages {
   const PARSER_ERROR: &'static str = "Parser Error";
   const PARSER_EXPECTED_GRAMMAR_RULES: &'static str = "Expected Grammar Rules";
   const TYPE_ERROR: &'static str = "Type Error";
+  const UNKNOWN_OPTION_ERROR: &'static str = "Unknown Option";
+  const IO_ERROR: &'static str = "File Error";
+  const MANIFEST_ERROR: &'static str = "Project File Error";
+  const IMPORT_ERROR: &'static str = "Import Error";
+  const INSTANCE_ERROR: &'static str = "Instance Error";
+  const REFERENCE_ERROR: &'static str = "Reference Error";
+  const FIELD_ERROR: &'static str = "Field Error";
+  const EVENT_ERROR: &'static str = "Event Error";
+  const NOT_RESOURCE_ERROR: &'static str = "Expected a single resource declaration";
+  const NO_PROJECT_FILE_ERROR: &'static str = "No project file was found, nothing to compile!";
+  const BREAK_IN_SWITCH_ERROR: &'static str = "Cases never fall through, 'break' can't be used to leave a switch";
+  const CONTROL_FLOW_ERROR: &'static str = "Control Flow Error";
+  const CONSTANT_ERROR: &'static str = "Constant Error";
+  const INHERITANCE_ERROR: &'static str = "Inheritance Error";
+  const TOP_MODULE: &'static str = "(top module)";
   const UNKNOWN_FILE: &'static str = "??????????";
 
   fn parser_small_error_message(kind: ParserErrorKind) -> &'static str {
@@ -26,4 +41,98 @@ impl ErrorMessageProvider for DefaultErrorMessages {
   fn type_small_error_message(ty: String) -> String {
     format!("Expression needed to be of type {}", ty)
   }
+
+  fn unknown_option_small_error_message(value: String, options: Vec<String>) -> String {
+    format!("'{}' is not one of: {}", value, options.join(", "))
+  }
+
+  fn manifest_small_error_message(key: String, kind: ManifestErrorKind) -> String {
+    match kind {
+      ManifestErrorKind::Syntax(message) => message,
+      ManifestErrorKind::UnknownKey => format!("Unknown key '{}'", key),
+      ManifestErrorKind::WrongType(ty) => format!("'{}' needs to be of type {}", key, ty),
+      ManifestErrorKind::UnknownValue(value, options) => {
+        format!("'{}' of '{}' is not one of: {}", value, key, options.join(", "))
+      }
+      ManifestErrorKind::UnknownRoom(room) => format!("There is no room called '{}'", room),
+    }
+  }
+
+  fn missing_import_small_error_message(name: String) -> String {
+    format!("Nothing called '{}' was declared in the project", name)
+  }
+
+  fn cyclic_import_small_error_message(cycle: Vec<String>) -> String {
+    format!("Modules import each other: {}", cycle.join(" -> "))
+  }
+
+  fn unknown_instance_small_error_message(name: String) -> String {
+    format!("No such instance '{}'", name)
+  }
+
+  fn unknown_reference_small_error_message(kind: String, name: String, suggestion: Option<String>) -> String {
+    match suggestion {
+      Some(suggestion) => format!("No such {} '{}', did you mean '{}'?", kind, name, suggestion),
+      None => format!("No such {} '{}'", kind, name),
+    }
+  }
+
+  fn invalid_field_small_error_message(field: String, kind: InvalidFieldKind) -> String {
+    use InvalidFieldKind::*;
+
+    match kind {
+      NotFound(_)       => format!("'{}' is not a field of this resource", field),
+      NotSimple(_)      => format!("'{}' can't be indexed or have sub fields", field),
+      NotSubResource(_) => format!("'{}' needs a sub field, like '{}.name'", field, field),
+      NotArray(_)       => format!("'{}' is a list, it needs an index like '{}[0]'", field, field),
+      EmptyRange(_)     => format!("This range of '{}' ends before it starts, 'last' can't be below 'first'", field),
+      MissingSubField(_, sub_field) => format!("Every entry of '{}' needs a '{}'", field, sub_field),
+      OutOfRange(_, size) => format!("'{}' only has {} entries, the index goes from 0 to {}", field, size, size - 1),
+    }
+  }
+
+  fn event_small_error_message(kind: EventErrorKind) -> String {
+    use EventErrorKind::*;
+
+    let event = kind.key().content.name_of();
+    match kind {
+      InvalidName(_)          => format!("'{}' is not an event", event),
+      InvalidIndexType(_, ty) => format!("The index of a {} event needs to be a {}", event, ty),
+      Dot(_)                  => String::from("Events can't have sub fields"),
+      UnknownStepKind(_)      => String::from("A step event is one of: \"normal\", \"begin\", \"end\""),
+      UnknownAlarmKind(_)     => String::from("Alarms go from 0 to 11"),
+      UnknownKeyCode(_)       => format!("Unknown key for a {} event", event),
+      UnknownMouseKind(_)     => String::from("Unknown mouse event"),
+      UnknownOtherKind(_)     => String::from("Unknown kind of other event"),
+      UnknownDrawKind(_)      => String::from("Unknown kind of draw event"),
+    }
+  }
+
+  fn outside_loop_small_error_message(statement: String) -> String {
+    format!("'{}' can only be used inside a loop", statement)
+  }
+
+  fn constant_small_error_message(kind: ConstantErrorKind) -> String {
+    use ConstantErrorKind::*;
+
+    match kind {
+      NotConstant    => String::from("Constants can only use numbers, strings, booleans, operators and other constants"),
+      WrongTypes     => String::from("The operator can't be used with values of these types"),
+      DivisionByZero => String::from("Division by zero"),
+      Cycle(cycle)   => format!("Constants depend on each other: {}", cycle.join(" -> ")),
+      NotInteger     => String::from("Enumeration values need to be integers"),
+
+      DuplicateVariant(variant) => format!("'{}' is already a variant of this enumeration", variant),
+      UnknownVariant(enumeration, variant) => format!("'{}' is not a variant of '{}'", variant, enumeration),
+    }
+  }
+
+  fn inheritance_small_error_message(kind: InheritanceErrorKind) -> String {
+    use InheritanceErrorKind::*;
+
+    match kind {
+      Cycle(cycle)         => format!("Objects inherit from each other: {}", cycle.join(" -> ")),
+      SuperOutsideOverride => String::from("'super()' can only be called in events and methods of objects with a parent"),
+    }
+  }
 }
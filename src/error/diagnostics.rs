@@ -0,0 +1,64 @@
+//! Errors as JSON objects, one per line, for editors and other tools
+//! reading the compiler output.
+//!
+//!   {"severity": "error", "code": "E0011", "file": "src/rooms.mgl",
+//!    "line_start": 3, "column_start": 5, "line_end": 3, "column_end": 10,
+//!    "message": "No such instance 'player'", "expected": []}
+
+use std::str::FromStr;
+use crate::compiler::json::*;
+use crate::error::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+  Human,
+  Json,
+}
+
+pub const ERROR_FORMAT_NAMES: &[&str] = &["human", "json"];
+
+impl FromStr for ErrorFormat {
+  type Err = MglError;
+
+  fn from_str(s: &str) -> Result<ErrorFormat> {
+    match s {
+      "human" => Ok(ErrorFormat::Human),
+      "json"  => Ok(ErrorFormat::Json),
+      _ => MglError::unknown_option(s, ERROR_FORMAT_NAMES)
+    }
+  }
+}
+
+impl ErrorFormat {
+  pub fn eprintln<P: ErrorMessageProvider>(self, e: MglError) {
+    match self {
+      ErrorFormat::Human => P::eprintln(e),
+      ErrorFormat::Json  => eprintln!("{}", diagnostic::<P>(e).compact()),
+    }
+  }
+}
+
+/// Positions the error doesn't have are `null`, `expected` lists the
+/// grammar rules a parser error could have continued with.
+pub fn diagnostic<P: ErrorMessageProvider>(e: MglError) -> Json {
+  let location = e.location();
+  let file     = location.as_ref().and_then(|l| l.file.as_ref()).map(|f| f.display().to_string());
+  let span     = location.and_then(|l| l.span);
+
+  let expected = match &e {
+    MglError::Parser { rules, .. } => rules.iter().map(|r| Json::from(format!("{:?}", r))).collect(),
+    _ => Vec::new()
+  };
+
+  json_object! {
+    "severity"     => "error",
+    "code"         => e.code(),
+    "file"         => file,
+    "line_start"   => span.map(|s| s.line_start),
+    "column_start" => span.map(|s| s.column_start),
+    "line_end"     => span.map(|s| s.line_end),
+    "column_end"   => span.map(|s| s.column_end),
+    "message"      => P::short_error_message(e),
+    "expected"     => expected,
+  }
+}
@@ -1,12 +1,16 @@
+use std::io;
+use std::path::{Path, PathBuf};
 use crate::error::Result;
 use crate::ast::*;
 use crate::parser::grammar::*;
+use crate::source_files::SourceFile;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MglError {
   Parser {
     error_kind: ParserErrorKind,
     verbose: bool,
+    file: SourceFile,
     text: String,
     line: usize,
     column: usize,
@@ -15,7 +19,7 @@ pub enum MglError {
 
   InvalidField {
     kind: InvalidFieldKind,
-    field: String
+    field: String,
   },
 
   ConvertExpression {
@@ -28,11 +32,64 @@ pub enum MglError {
   UnknownOption {
     value: String,
     options: Vec<String>,
+    location: Option<IExpr>,
   },
 
   Event {
     kind: EventErrorKind,
-  }
+  },
+
+  Io {
+    path: PathBuf,
+    message: String,
+  },
+
+  Manifest {
+    kind: ManifestErrorKind,
+    key: String,
+    path: PathBuf,
+    line: usize,
+    column: usize,
+  },
+
+  MissingImport {
+    import: IExpr,
+  },
+
+  CyclicImport {
+    import: IExpr,
+    cycle: Vec<Vec<String>>,
+  },
+
+  UnknownInstance {
+    name: AstDebugInfo<ResourceName>,
+  },
+
+  UnknownReference {
+    kind: String,
+    name: AstDebugInfo<ResourceName>,
+    suggestion: Option<String>,
+  },
+
+  OutsideLoop {
+    statement: IStat,
+  },
+
+  Constant {
+    kind: ConstantErrorKind,
+    expression: IExpr,
+  },
+
+  Inheritance {
+    kind: InheritanceErrorKind,
+    expression: IExpr,
+  },
+
+  NoProjectFile,
+
+  BreakInSwitch {
+    statement: IStat,
+  },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -49,26 +106,146 @@ pub enum ParserErrorKind {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InvalidFieldKind {
-  NotFound,
-  NotSimple(Key),
-  NotSubResource(Key),
-  NotArray(Key),
+  NotFound(IKey),
+  NotSimple(IKey),
+  NotSubResource(IKey),
+  NotArray(IKey),
+  EmptyRange(IKey),
+  MissingSubField(IKey, String),
+  OutOfRange(IKey, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstantErrorKind {
+  NotConstant,
+  WrongTypes,
+  DivisionByZero,
+  Cycle(Vec<String>),
+  NotInteger,
+  DuplicateVariant(String),
+  UnknownVariant(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InheritanceErrorKind {
+  Cycle(Vec<String>),
+  SuperOutsideOverride,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestErrorKind {
+  Syntax(String),
+  UnknownKey,
+  WrongType(String),
+  UnknownValue(String, Vec<String>),
+  UnknownRoom(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EventErrorKind {
-  InvalidName,
-  InvalidIndexType(String),
-  Dot,
-  UnknownStepKind,
-  UnknownAlarmKind,
-  UnknownKeyCode,
-  UnknownMouseKind,
-  UnknownOtherKind,
-  UnknownDrawKind,
+  InvalidName(IKey),
+  InvalidIndexType(IKey, String),
+  Dot(IKey),
+  UnknownStepKind(IKey),
+  UnknownAlarmKind(IKey),
+  UnknownKeyCode(IKey),
+  UnknownMouseKind(IKey),
+  UnknownOtherKind(IKey),
+  UnknownDrawKind(IKey),
+}
+
+/// Where an error happened, lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorLocation {
+  pub file: Option<PathBuf>,
+  pub span: Option<ErrorSpan>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorSpan {
+  pub line_start: usize,
+  pub column_start: usize,
+  pub line_end: usize,
+  pub column_end: usize,
+}
+
+impl ErrorLocation {
+  fn of<T>(ast: &AstDebugInfo<T>) -> Self {
+    let &AstDebugInfo { file, line_start, column_start, line_end, column_end, .. } = ast;
+
+    ErrorLocation {
+      file: file.as_path(),
+      span: if line_start == 0 { None } else {
+        Some(ErrorSpan { line_start, column_start, line_end, column_end })
+      }
+    }
+  }
+
+  fn at(file: Option<PathBuf>, line: usize, column: usize) -> Self {
+    ErrorLocation {
+      file,
+      span: Some(ErrorSpan { line_start: line, column_start: column, line_end: line, column_end: column })
+    }
+  }
 }
 
 impl MglError {
+  /// Identifies the kind of error for tools reading the compiler output,
+  /// codes never change meaning once given.
+  pub fn code(&self) -> &'static str {
+    match self {
+      MglError::Parser {..}            => "E0001",
+      MglError::InvalidField {..}      => "E0002",
+      MglError::ConvertExpression {..} => "E0003",
+      MglError::NotResource            => "E0004",
+      MglError::UnknownOption {..}     => "E0005",
+      MglError::Event {..}             => "E0006",
+      MglError::Io {..}                => "E0007",
+      MglError::Manifest {..}          => "E0008",
+      MglError::MissingImport {..}     => "E0009",
+      MglError::CyclicImport {..}      => "E0010",
+      MglError::UnknownInstance {..}   => "E0011",
+      MglError::UnknownReference {..}  => "E0012",
+      MglError::OutsideLoop {..}       => "E0013",
+      MglError::Constant {..}          => "E0014",
+      MglError::Inheritance {..}       => "E0015",
+      MglError::NoProjectFile          => "E0016",
+      MglError::BreakInSwitch {..}     => "E0017",
+    }
+  }
+
+  pub fn location(&self) -> Option<ErrorLocation> {
+    match self {
+      MglError::Parser { file, line, column, .. } => {
+        Some(ErrorLocation::at(file.as_path(), *line, *column))
+      }
+
+      MglError::Manifest { path, line, column, .. } => {
+        Some(ErrorLocation::at(Some(path.clone()), *line, *column))
+      }
+
+      MglError::Io { path, .. } => {
+        Some(ErrorLocation { file: Some(path.clone()), span: None })
+      }
+
+      MglError::InvalidField { kind, .. }         => Some(ErrorLocation::of(kind.key())),
+      MglError::ConvertExpression { value, .. }   => Some(ErrorLocation::of(value)),
+      MglError::UnknownOption { location, .. }    => location.as_ref().map(ErrorLocation::of),
+      MglError::Event { kind }                    => Some(kind.index().map(ErrorLocation::of)
+                                                                   .unwrap_or(ErrorLocation::of(kind.key()))),
+      MglError::MissingImport { import }          => Some(ErrorLocation::of(import)),
+      MglError::CyclicImport { import, .. }       => Some(ErrorLocation::of(import)),
+      MglError::UnknownInstance { name }          => Some(ErrorLocation::of(name)),
+      MglError::UnknownReference { name, .. }     => Some(ErrorLocation::of(name)),
+      MglError::OutsideLoop { statement }         => Some(ErrorLocation::of(statement)),
+      MglError::BreakInSwitch { statement }       => Some(ErrorLocation::of(statement)),
+      MglError::Constant { expression, .. }       => Some(ErrorLocation::of(expression)),
+      MglError::Inheritance { expression, .. }    => Some(ErrorLocation::of(expression)),
+      MglError::NotResource                       => None,
+      MglError::NoProjectFile                     => None,
+    }
+  }
+
   pub fn event<T>(kind: EventErrorKind) -> Result<T> {
     Err(MglError::Event { kind })
   }
@@ -96,7 +273,62 @@ impl MglError {
       MglError::UnknownOption {
         value: String::from(value),
         options: options.iter().map(|x| String::from(*x)).collect(),
+        location: None,
       }
     )
   }
+
+  /// An unknown option written in the source, rather than given in the command line.
+  pub fn unknown_option_at<T>(location: &IExpr, value: &str, options: &[&str]) -> Result<T> {
+    Err(
+      MglError::UnknownOption {
+        value: String::from(value),
+        options: options.iter().map(|x| String::from(*x)).collect(),
+        location: Some(location.clone()),
+      }
+    )
+  }
+
+  pub fn io<T>(path: &Path, error: io::Error) -> Result<T> {
+    Err(
+      MglError::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+      }
+    )
+  }
+}
+
+impl InvalidFieldKind {
+  /// The key the field was written with.
+  pub fn key(&self) -> &IKey {
+    use InvalidFieldKind::*;
+
+    match self {
+      NotFound(key) | NotSimple(key) | NotSubResource(key) | NotArray(key) | EmptyRange(key) => key,
+      MissingSubField(key, _) | OutOfRange(key, _) => key,
+    }
+  }
+}
+
+impl EventErrorKind {
+  /// The key the event was written with.
+  pub fn key(&self) -> &IKey {
+    use EventErrorKind::*;
+
+    match self {
+      InvalidName(key) | InvalidIndexType(key, _) | Dot(key) | UnknownStepKind(key) |
+      UnknownAlarmKind(key) | UnknownKeyCode(key) | UnknownMouseKind(key) |
+      UnknownOtherKind(key) | UnknownDrawKind(key) => key
+    }
+  }
+
+  /// The index of the event when it's what is wrong, a wrong name or sub field
+  /// is shown through the whole key instead.
+  pub fn index(&self) -> Option<&IExpr> {
+    match self {
+      EventErrorKind::InvalidName(_) | EventErrorKind::Dot(_) => None,
+      _ => self.key().content.index_of()
+    }
+  }
 }
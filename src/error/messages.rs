@@ -12,10 +12,36 @@ pub trait ErrorMessageProvider {
   const PARSER_EXPECTED_GRAMMAR_RULES: &'static str;
 
   const TYPE_ERROR: &'static str;
+  const UNKNOWN_OPTION_ERROR: &'static str;
+  const IO_ERROR: &'static str;
+  const MANIFEST_ERROR: &'static str;
+  const IMPORT_ERROR: &'static str;
+  const INSTANCE_ERROR: &'static str;
+  const REFERENCE_ERROR: &'static str;
+  const FIELD_ERROR: &'static str;
+  const EVENT_ERROR: &'static str;
+  const NOT_RESOURCE_ERROR: &'static str;
+  const NO_PROJECT_FILE_ERROR: &'static str;
+  const BREAK_IN_SWITCH_ERROR: &'static str;
+  const CONTROL_FLOW_ERROR: &'static str;
+  const CONSTANT_ERROR: &'static str;
+  const INHERITANCE_ERROR: &'static str;
+  const TOP_MODULE: &'static str;
   const UNKNOWN_FILE: &'static str;
 
   fn parser_small_error_message(kind: ParserErrorKind) -> &'static str;
   fn type_small_error_message(ty: String) -> String;
+  fn unknown_option_small_error_message(value: String, options: Vec<String>) -> String;
+  fn manifest_small_error_message(key: String, kind: ManifestErrorKind) -> String;
+  fn missing_import_small_error_message(name: String) -> String;
+  fn cyclic_import_small_error_message(cycle: Vec<String>) -> String;
+  fn unknown_instance_small_error_message(name: String) -> String;
+  fn unknown_reference_small_error_message(kind: String, name: String, suggestion: Option<String>) -> String;
+  fn invalid_field_small_error_message(field: String, kind: InvalidFieldKind) -> String;
+  fn event_small_error_message(kind: EventErrorKind) -> String;
+  fn outside_loop_small_error_message(statement: String) -> String;
+  fn constant_small_error_message(kind: ConstantErrorKind) -> String;
+  fn inheritance_small_error_message(kind: InheritanceErrorKind) -> String;
 
 
   // Provided methods
@@ -28,10 +54,89 @@ pub trait ErrorMessageProvider {
     match &e {
       MglError::Parser {..}            => Self::parser_error_message(e),
       MglError::ConvertExpression {..} => Self::type_error_message(e),
-      _ => unimplemented!()
+      MglError::UnknownOption {..}     => Self::unknown_option_message(e),
+      MglError::Io {..}                => Self::io_error_message(e),
+      MglError::Manifest {..}          => Self::manifest_error_message(e),
+      MglError::MissingImport {..}     => Self::import_error_message(e),
+      MglError::CyclicImport {..}      => Self::import_error_message(e),
+      MglError::UnknownInstance {..}   => Self::unknown_instance_message(e),
+      MglError::UnknownReference {..}  => Self::unknown_reference_message(e),
+      MglError::InvalidField {..}      => Self::invalid_field_message(e),
+      MglError::Event {..}             => Self::event_error_message(e),
+      MglError::OutsideLoop {..}       => Self::control_flow_message(e),
+      MglError::BreakInSwitch {..}     => Self::control_flow_message(e),
+      MglError::Constant {..}          => Self::constant_error_message(e),
+      MglError::Inheritance {..}       => Self::inheritance_error_message(e),
+      MglError::NotResource            => format!("{}\n", Self::NOT_RESOURCE_ERROR),
+      MglError::NoProjectFile          => format!("{}\n", Self::NO_PROJECT_FILE_ERROR),
     }
   }
 
+  /// What's wrong, in a single line and without the code it happened at.
+  fn short_error_message(e: MglError) -> String {
+    match e {
+      MglError::Parser { error_kind, .. } => {
+        String::from(Self::parser_small_error_message(error_kind))
+      }
+
+      MglError::ConvertExpression { into_type, .. } => Self::type_small_error_message(into_type),
+      MglError::UnknownOption { value, options, .. } => Self::unknown_option_small_error_message(value, options),
+      MglError::Io { message, .. } => message,
+      MglError::Manifest { key, kind, .. } => Self::manifest_small_error_message(key, kind),
+
+      MglError::MissingImport { import } => {
+        let name = match import.content_clone() {
+          Expression::Resource(name) => name.to_string(),
+          _ => String::new()
+        };
+        Self::missing_import_small_error_message(name)
+      }
+
+      MglError::CyclicImport { cycle, .. } => {
+        let modules = cycle.iter().map(|module| {
+          if module.is_empty() { String::from(Self::TOP_MODULE) } else { module.join("::") }
+        });
+        Self::cyclic_import_small_error_message(modules.collect())
+      }
+
+      MglError::UnknownInstance { name } => {
+        Self::unknown_instance_small_error_message(name.content.to_string())
+      }
+
+      MglError::UnknownReference { kind, name, suggestion } => {
+        Self::unknown_reference_small_error_message(kind, name.content.to_string(), suggestion)
+      }
+
+      MglError::InvalidField { field, kind, .. } => Self::invalid_field_small_error_message(field, kind),
+      MglError::Event { kind } => Self::event_small_error_message(kind),
+      MglError::NotResource => String::from(Self::NOT_RESOURCE_ERROR),
+      MglError::NoProjectFile => String::from(Self::NO_PROJECT_FILE_ERROR),
+      MglError::BreakInSwitch {..} => String::from(Self::BREAK_IN_SWITCH_ERROR),
+
+      MglError::OutsideLoop { statement } => {
+        let keyword = match statement.as_ref() {
+          Statement::Continue => "continue",
+          _ => "break",
+        };
+        Self::outside_loop_small_error_message(String::from(keyword))
+      }
+
+      MglError::Constant { kind, .. } => Self::constant_small_error_message(kind),
+      MglError::Inheritance { kind, .. } => Self::inheritance_small_error_message(kind),
+    }
+  }
+
+  /// A title, the code at `location` and what's wrong with it.
+  fn located_message<T>(title: &str, location: &AstDebugInfo<T>, message: String) -> String {
+    let mut result = String::new();
+    result.push_str(&format!("{}:\n", title));
+    result.push_str(&Self::show_ast_location(location)
+                    .unwrap_or(String::from(Self::UNKNOWN_FILE)));
+    result.push_str(&message);
+    result.push('\n');
+    result
+  }
+
   fn parser_error_message(e: MglError) -> String {
     let long;
     let kind;
@@ -116,14 +221,142 @@ pub trait ErrorMessageProvider {
 
   fn type_error_message(e: MglError) -> String {
     if let MglError::ConvertExpression  { value, into_type } = e {
-      let mut result = String::new();
-      result.push_str(&format!("{}:\n", Self::TYPE_ERROR));
-      result.push_str(&Self::show_ast_location(&value)
-                      .unwrap_or(String::from(Self::UNKNOWN_FILE)));
+      Self::located_message(Self::TYPE_ERROR, &value, Self::type_small_error_message(into_type))
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn unknown_option_message(e: MglError) -> String {
+    if let MglError::UnknownOption { value, options, location } = e {
+      let message = Self::unknown_option_small_error_message(value, options);
+
+      match location {
+        Some(location) => Self::located_message(Self::UNKNOWN_OPTION_ERROR, &location, message),
+        None => format!("{}:\n      {}\n", Self::UNKNOWN_OPTION_ERROR, message),
+      }
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn io_error_message(e: MglError) -> String {
+    if let MglError::Io { path, message } = e {
+      format!("{}:\n      [{}]\n      {}\n",
+              Self::IO_ERROR,
+              path.to_str().unwrap_or(Self::UNKNOWN_FILE),
+              message)
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn manifest_error_message(e: MglError) -> String {
+    if let MglError::Manifest { kind, key, path, line, column } = e {
+      let text = fs::read_to_string(&path).unwrap_or(String::new());
+      let text = text.lines().nth(line - 1).unwrap_or("");
+
+      format!("{}:\n      [{}]\n      |\n {:>4} | {}\n      | {}^\n      {}\n",
+              Self::MANIFEST_ERROR,
+              path.to_str().unwrap_or(Self::UNKNOWN_FILE),
+              line,
+              text,
+              " ".repeat(column - 1),
+              Self::manifest_small_error_message(key, kind))
+
+    } else {
+      unreachable!()
+    }
+  }
 
-      result.push_str(&Self::type_small_error_message(into_type));
-      result.push('\n');
-      result
+
+  fn import_error_message(e: MglError) -> String {
+    let import = match &e {
+      MglError::MissingImport { import } | MglError::CyclicImport { import, .. } => import.clone(),
+      _ => unreachable!()
+    };
+
+    Self::located_message(Self::IMPORT_ERROR, &import, Self::short_error_message(e))
+  }
+
+
+  fn unknown_instance_message(e: MglError) -> String {
+    if let MglError::UnknownInstance { name } = e {
+      let message = Self::unknown_instance_small_error_message(name.content.to_string());
+      Self::located_message(Self::INSTANCE_ERROR, &name, message)
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn unknown_reference_message(e: MglError) -> String {
+    if let MglError::UnknownReference { kind, name, suggestion } = e {
+      let message = Self::unknown_reference_small_error_message(kind, name.content.to_string(), suggestion);
+      Self::located_message(Self::REFERENCE_ERROR, &name, message)
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn invalid_field_message(e: MglError) -> String {
+    if let MglError::InvalidField { kind, field } = e {
+      let key = kind.key().clone();
+      Self::located_message(Self::FIELD_ERROR, &key, Self::invalid_field_small_error_message(field, kind))
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn event_error_message(e: MglError) -> String {
+    if let MglError::Event { kind } = e {
+      let message = Self::event_small_error_message(kind.clone());
+
+      match kind.index() {
+        Some(index) => Self::located_message(Self::EVENT_ERROR, index, message),
+        None        => Self::located_message(Self::EVENT_ERROR, kind.key(), message),
+      }
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn control_flow_message(e: MglError) -> String {
+    if let MglError::OutsideLoop { statement } | MglError::BreakInSwitch { statement } = &e {
+      Self::located_message(Self::CONTROL_FLOW_ERROR, statement, Self::short_error_message(e.clone()))
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn constant_error_message(e: MglError) -> String {
+    if let MglError::Constant { kind, expression } = e {
+      Self::located_message(Self::CONSTANT_ERROR, &expression, Self::constant_small_error_message(kind))
+
+    } else {
+      unreachable!()
+    }
+  }
+
+
+  fn inheritance_error_message(e: MglError) -> String {
+    if let MglError::Inheritance { kind, expression } = e {
+      Self::located_message(Self::INHERITANCE_ERROR, &expression, Self::inheritance_small_error_message(kind))
 
     } else {
       unreachable!()
@@ -134,4 +367,3 @@ pub trait ErrorMessageProvider {
 fn titlecase_rules(rules: &[Rule]) -> Vec<String> {
   rules.iter().map(|r| titlecase(&format!("{:?}", r).replace("_", " "))).collect()
 }
-
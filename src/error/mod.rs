@@ -7,6 +7,9 @@ pub use messages::*;
 pub mod default_messages;
 pub use default_messages::*;
 
+pub mod diagnostics;
+pub use diagnostics::*;
+
 pub type Result<T>    = std::result::Result<T, MglError>;
 pub type TopResult<T> = std::result::Result<T, Vec<MglError>>;
 
@@ -1,5 +1,5 @@
 pub mod enums;
 pub mod parse;
+pub mod numbers;
 pub use enums::*;
 pub use parse::*;
-
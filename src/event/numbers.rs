@@ -0,0 +1,194 @@
+//! The numbers Game Maker uses to identify each event, shared by all exporters.
+
+use crate::event::enums::*;
+
+impl Event {
+  pub fn event_type(&self) -> u32 {
+    match self {
+      Event::Create        => 0,
+      Event::Destroy       => 1,
+      Event::Alarm(_)      => 2,
+      Event::Step(_)       => 3,
+      Event::Collision(_)  => 4,
+      Event::Keyboard(_)   => 5,
+      Event::Mouse(_)      => 6,
+      Event::Other(_)      => 7,
+      Event::Draw(_)       => 8,
+      Event::KeyPress(_)   => 9,
+      Event::KeyRelease(_) => 10,
+    }
+  }
+
+  /// Collision events are identified by the object instead, so they get `0`.
+  pub fn event_number(&self) -> u32 {
+    match self {
+      Event::Create | Event::Destroy | Event::Collision(_) => 0,
+
+      Event::Alarm(alarm)    => *alarm as u32,
+      Event::Step(kind)      => kind.number(),
+      Event::Keyboard(key)   => key.number(),
+      Event::KeyPress(key)   => key.number(),
+      Event::KeyRelease(key) => key.number(),
+      Event::Mouse(action)   => action.number(),
+      Event::Other(other)    => other.number(),
+      Event::Draw(kind)      => kind.number(),
+    }
+  }
+}
+
+impl StepKind {
+  pub fn number(self) -> u32 {
+    match self {
+      StepKind::Normal => 0,
+      StepKind::Begin  => 1,
+      StepKind::End    => 2,
+    }
+  }
+}
+
+impl DrawKind {
+  pub fn number(self) -> u32 {
+    match self {
+      DrawKind::Gui      => 64,
+      DrawKind::Begin    => 72,
+      DrawKind::End      => 73,
+      DrawKind::GuiBegin => 74,
+      DrawKind::GuiEnd   => 75,
+      DrawKind::Pre      => 76,
+      DrawKind::Post     => 77,
+    }
+  }
+}
+
+impl OtherEvent {
+  pub fn number(self) -> u32 {
+    use OtherEvent::*;
+
+    match self {
+      RoomOutside  => 0,
+      RoomBoundary => 1,
+      GameStart    => 2,
+      GameEnd      => 3,
+      RoomStart    => 4,
+      RoomEnd      => 5,
+      NoMoreLives  => 6,
+      AnimationEnd => 7,
+      EndOfPath    => 8,
+      NoMoreHealth => 9,
+      CloseButton  => 30,
+      User0        => 10,
+      User1        => 11,
+      User2        => 12,
+      User3        => 13,
+      User4        => 14,
+      User5        => 15,
+      User6        => 16,
+      User7        => 17,
+      User8        => 18,
+      User9        => 19,
+      User10       => 20,
+      User11       => 21,
+      User12       => 22,
+      User13       => 23,
+      User14       => 24,
+      User15       => 25,
+    }
+  }
+}
+
+impl MouseAction {
+  pub fn number(self) -> u32 {
+    use MouseAction::*;
+
+    match self {
+      LeftButton          => 0,
+      RightButton         => 1,
+      MiddleButton        => 2,
+      NoButton            => 3,
+      LeftPress           => 4,
+      RightPress          => 5,
+      MiddlePress         => 6,
+      LeftRelease         => 7,
+      RightRelease        => 8,
+      MiddleRelease       => 9,
+      MouseEnter          => 10,
+      MouseLeave          => 11,
+      GlobalLeftButton    => 50,
+      GlobalRightButton   => 51,
+      GlobalMiddleButton  => 52,
+      GlobalLeftPress     => 53,
+      GlobalRightPress    => 54,
+      GlobalMiddlePress   => 55,
+      GlobalLeftRelease   => 56,
+      GlobalRightRelease  => 57,
+      GlobalMiddleRelease => 58,
+      MouseWheelUp        => 60,
+      MouseWheelDown      => 61,
+    }
+  }
+}
+
+impl KeyCode {
+  pub fn number(self) -> u32 {
+    use KeyCode::*;
+
+    match self {
+      NoKey        => 0,
+      AnyKey       => 1,
+      Character(c) => c.to_ascii_uppercase() as u32,
+      Backspace    => 8,
+      Tab          => 9,
+      Enter        => 13,
+      Shift        => 16,
+      Control      => 17,
+      Alt          => 18,
+      Pause        => 19,
+      Escape       => 27,
+      Space        => 32,
+      PageUp       => 33,
+      PageDown     => 34,
+      End          => 35,
+      Home         => 36,
+      Left         => 37,
+      Up           => 38,
+      Right        => 39,
+      Down         => 40,
+      PrintScreen  => 44,
+      Insert       => 45,
+      Delete       => 46,
+      NumPad0      => 96,
+      NumPad1      => 97,
+      NumPad2      => 98,
+      NumPad3      => 99,
+      NumPad4      => 100,
+      NumPad5      => 101,
+      NumPad6      => 102,
+      NumPad7      => 103,
+      NumPad8      => 104,
+      NumPad9      => 105,
+      Multiply     => 106,
+      Add          => 107,
+      Subtract     => 109,
+      Decimal      => 110,
+      Divide       => 111,
+      F1           => 112,
+      F2           => 113,
+      F3           => 114,
+      F4           => 115,
+      F5           => 116,
+      F6           => 117,
+      F7           => 118,
+      F8           => 119,
+      F9           => 120,
+      F10          => 121,
+      F11          => 122,
+      F12          => 123,
+      LeftShift    => 160,
+      RightShift   => 161,
+      LeftControl  => 162,
+      RightControl => 163,
+      LeftAlt      => 164,
+      RightAlt     => 165,
+    }
+  }
+}
@@ -7,47 +7,47 @@ use EventErrorKind::*;
 
 type Result<T> = std::result::Result<T, EventErrorKind>;
 
-impl TryFrom<Key> for Event {
+impl TryFrom<IKey> for Event {
   type Error = EventErrorKind;
 
-  fn try_from(value: Key) -> Result<Self> {
-    if value.is_dot() {
-      return Err(Dot);
+  fn try_from(key: IKey) -> Result<Self> {
+    if key.content.is_dot() {
+      return Err(Dot(key));
     }
 
-    match &*value.name_of() {
+    match &*key.content.name_of() {
       "create"     => Ok(Event::Create),
       "destroy"    => Ok(Event::Destroy),
-      "step"       => Ok(Event::Step(parse_step_kind(value)?)),
-      "alarm"      => Ok(Event::Alarm(parse_alarm_kind(value)?)),
-      "keyboard"   => Ok(Event::Keyboard(parse_key_code(value)?)),
-      "keypress"   => Ok(Event::KeyPress(parse_key_code(value)?)),
-      "keyrelease" => Ok(Event::KeyRelease(parse_key_code(value)?)),
-      "mouse"      => Ok(Event::Mouse(parse_mouse_action(value)?)),
-      "collision"  => Ok(Event::Collision(parse_object_name(value)?)),
-      "other"      => Ok(Event::Other(parse_other_event(value)?)),
-      "draw"       => Ok(Event::Draw(parse_draw_kind(value)?)),
-      _ => Err(InvalidName)
+      "step"       => Ok(Event::Step(parse_step_kind(&key)?)),
+      "alarm"      => Ok(Event::Alarm(parse_alarm_kind(&key)?)),
+      "keyboard"   => Ok(Event::Keyboard(parse_key_code(&key)?)),
+      "keypress"   => Ok(Event::KeyPress(parse_key_code(&key)?)),
+      "keyrelease" => Ok(Event::KeyRelease(parse_key_code(&key)?)),
+      "mouse"      => Ok(Event::Mouse(parse_mouse_action(&key)?)),
+      "collision"  => Ok(Event::Collision(parse_object_name(&key)?)),
+      "other"      => Ok(Event::Other(parse_other_event(&key)?)),
+      "draw"       => Ok(Event::Draw(parse_draw_kind(&key)?)),
+      _ => Err(InvalidName(key))
     }
   }
 }
 
-fn parse_step_kind(value: Key) -> Result<StepKind> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_step_kind(key: &IKey) -> Result<StepKind> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Str(name) => {
       match name.as_ref() {
         "normal" => Ok(StepKind::Normal),
         "begin"  => Ok(StepKind::Begin),
         "end"    => Ok(StepKind::End),
-        _ => Err(UnknownStepKind)
+        _ => Err(UnknownStepKind(key.clone()))
       }
     }
-    _ => Err(InvalidIndexType(String::from("string")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("string")))
   }
 }
 
-fn parse_alarm_kind(value: Key) -> Result<Alarm> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_alarm_kind(key: &IKey) -> Result<Alarm> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Num(n) => {
       match n.as_ref() {
          "0" => Ok(Alarm::Alarm0),
@@ -62,15 +62,15 @@ fn parse_alarm_kind(value: Key) -> Result<Alarm> {
          "9" => Ok(Alarm::Alarm9),
         "10" => Ok(Alarm::Alarm10),
         "11" => Ok(Alarm::Alarm11),
-        _ => Err(UnknownAlarmKind)
+        _ => Err(UnknownAlarmKind(key.clone()))
       }
     }
-    _ => Err(InvalidIndexType(String::from("number")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("number")))
   }
 }
 
-fn parse_key_code(value: Key) -> Result<KeyCode> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_key_code(key: &IKey) -> Result<KeyCode> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Str(name) => {
       if name.len() == 1 && name.chars().all(char::is_alphabetic) {
         Ok(KeyCode::Character(name.chars().next().unwrap()))
@@ -132,16 +132,16 @@ fn parse_key_code(value: Key) -> Result<KeyCode> {
           "rshift"       => Ok(KeyCode::RightShift),
           "rcontrol"     => Ok(KeyCode::RightControl),
           "ralt"         => Ok(KeyCode::RightAlt),
-          _ => Err(UnknownKeyCode)
+          _ => Err(UnknownKeyCode(key.clone()))
         }
       }
     }
-    _ => Err(InvalidIndexType(String::from("string")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("string")))
   }
 }
 
-fn parse_mouse_action(value: Key) -> Result<MouseAction> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_mouse_action(key: &IKey) -> Result<MouseAction> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Str(name) => {
       match name.as_ref() {
         "no_button"             => Ok(MouseAction::NoButton),
@@ -167,15 +167,15 @@ fn parse_mouse_action(value: Key) -> Result<MouseAction> {
         "global_left_release"   => Ok(MouseAction::GlobalLeftRelease),
         "global_right_release"  => Ok(MouseAction::GlobalRightRelease),
         "global_middle_release" => Ok(MouseAction::GlobalMiddleRelease),
-        _ => Err(UnknownMouseKind)
+        _ => Err(UnknownMouseKind(key.clone()))
       }
     }
-    _ => Err(InvalidIndexType(String::from("string")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("string")))
   }
 }
 
-fn parse_object_name(value: Key) -> Result<ResourceName> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_object_name(key: &IKey) -> Result<ResourceName> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Name(name) => {
       Ok(ResourceName::new(&["object", &name]))
     }
@@ -189,12 +189,12 @@ fn parse_object_name(value: Key) -> Result<ResourceName> {
       }
     }
 
-    _ => Err(InvalidIndexType(String::from("resource name")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("resource name")))
   }
 }
 
-fn parse_other_event(value: Key) -> Result<OtherEvent> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_other_event(key: &IKey) -> Result<OtherEvent> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Str(name) => {
       match name.as_ref() {
         "outside"        => Ok(OtherEvent::RoomOutside),
@@ -224,15 +224,15 @@ fn parse_other_event(value: Key) -> Result<OtherEvent> {
         "user13"         => Ok(OtherEvent::User13),
         "user14"         => Ok(OtherEvent::User14),
         "user15"         => Ok(OtherEvent::User15),
-        _ => Err(UnknownOtherKind)
+        _ => Err(UnknownOtherKind(key.clone()))
       }
     }
-    _ => Err(InvalidIndexType(String::from("string")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("string")))
   }
 }
 
-fn parse_draw_kind(value: Key) -> Result<DrawKind> {
-  match value.index_of().unwrap().as_ref() {
+fn parse_draw_kind(key: &IKey) -> Result<DrawKind> {
+  match key.content.index_of().unwrap().as_ref() {
     Expression::Str(name) => {
       match name.as_ref() {
         "begin"     => Ok(DrawKind::Begin),
@@ -242,10 +242,10 @@ fn parse_draw_kind(value: Key) -> Result<DrawKind> {
         "gui"       => Ok(DrawKind::Gui),
         "gui_begin" => Ok(DrawKind::GuiBegin),
         "gui_end"   => Ok(DrawKind::GuiEnd),
-        _ => Err(UnknownDrawKind)
+        _ => Err(UnknownDrawKind(key.clone()))
       }
     }
-    _ => Err(InvalidIndexType(String::from("string")))
+    _ => Err(InvalidIndexType(key.clone(), String::from("string")))
   }
 }
 
@@ -12,84 +12,140 @@ mod resources;
 mod command_line;
 mod compiler;
 mod source_files;
+mod manifest;
 
 #[cfg(test)]
 mod tests;
 
-use error::{MglError, ErrorMessageProvider, DefaultErrorMessages};
+use error::{MglError, ErrorFormat, DefaultErrorMessages};
 use resources::script::Script;
-use resources::project::{Project, Item};
+use resources::project::{Project, Item, Items};
 use command_line::{interpret_arguments, Action, Command};
+use manifest::ProjectManifest;
 use compiler::file_reader::*;
 use compiler::resource_tree::*;
 use compiler::script::*;
+use compiler::export::*;
 
 fn main() {
   let command = interpret_arguments();
+  let format  = command.error_format;
 
-  match command.action {
-    Action::Compile => {
-      println!("Not functional yet, sorry!");
+  // any error reported fails the command, so that scripts and CI can tell
+  let result = match command.action {
+    Action::Compile(ref output, target) => {
+      let output = output.clone();
+
+      if let Some(project_file) = command.project_file.clone() {
+        match ProjectManifest::from_file(&project_file) {
+          Ok(manifest) => {
+            report_project_errors(read_manifest_resource_tree(&manifest), format).and_then(|tree| {
+              match export_project(&tree, &manifest, output, target) {
+                Ok(directory) => {
+                  println!("Project written to '{}'", directory.display());
+                  Ok(())
+                }
+                Err(error) => report_errors(format, vec![error]),
+              }
+            })
+          }
+          Err(errors) => report_errors(format, errors)
+        }
+      } else {
+        report_errors(format, vec![MglError::NoProjectFile])
+      }
     }
 
     Action::Project(pretty) => {
-      if let Ok(tree) = read_project(command) {
+      read_project(command).map(|tree| {
         if pretty {
           println!("{:#?}", tree);
         } else {
           println!("{:?}", tree);
         }
-      }
+      })
     }
 
     Action::ShowAst(pretty) => {
       (|| {
-        let asts  = AstFileTree::from_project(command.project_file)?;
-        let other = command.files.iter().map(AstFileTree::from_file);
+        let (asts, mut errors) = AstFileTree::from_project(command.project_file)?;
         asts.print(pretty);
-        other.for_each(|ast| ast.unwrap().print(pretty));
-        Ok(())
 
-      })().err().map(|es: Vec<MglError>| for e in es { DefaultErrorMessages::eprintln(e) });
+        for file in &command.files {
+          let (ast, mut e) = AstFileTree::from_file(file);
+          ast.print(pretty);
+          errors.append(&mut e);
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+
+      })().or_else(|es| report_errors(format, es))
     }
 
     Action::Scripts => {
-      if let Ok(tree) = read_project(command) {
-        for script in tree.scripts {
-          print_script(script);
-        }
-      }
+      read_project(command).and_then(|tree| print_scripts(format, tree.scripts))
     }
+  };
+
+  if result.is_err() {
+    std::process::exit(1);
   }
 }
 
-fn print_script(script: Item<Script>) {
+/// Prints every script, even after one fails to build.
+fn print_scripts(format: ErrorFormat, scripts: Items<Script>) -> Result<(), ()> {
+  let mut result = Ok(());
+  for script in scripts {
+    result = result.and(print_script(format, script));
+  }
+  result
+}
+
+fn print_script(format: ErrorFormat, script: Item<Script>) -> Result<(), ()> {
   match script {
     Item::Group(name, items) => {
       println!("[{}]", name);
-      for item in items {
-        print_script(item);
-      }
+      print_scripts(format, items)
     }
     Item::File(name, s) => {
       println!("[[{}]]", name);
-      println!("{}", build_script(s));
+      match build_script(s) {
+        Ok(script) => {
+          println!("{}", script);
+          Ok(())
+        }
+        Err(error) => report_errors(format, vec![error]),
+      }
     }
   }
 }
 
 fn read_project(command: Command) -> Result<Project, ()> {
-  match read_resource_tree(command.project_file) {
+  report_project_errors(read_resource_tree(command.project_file), command.error_format)
+}
+
+fn report_error(format: ErrorFormat, error: MglError) {
+  format.eprintln::<DefaultErrorMessages>(error)
+}
+
+fn report_errors<T>(format: ErrorFormat, errors: Vec<MglError>) -> Result<T, ()> {
+  for error in errors {
+    report_error(format, error);
+  }
+  Err(())
+}
+
+fn report_project_errors(project: Result<Project, Vec<MglError>>, format: ErrorFormat) -> Result<Project, ()> {
+  match project {
     Ok(tree) => {
       Ok(tree)
     }
 
     Err(errors) => {
-      eprintln!("There were errors reading the project!");
-      for error in errors {
-        DefaultErrorMessages::eprintln(error);
+      if format == ErrorFormat::Human {
+        eprintln!("There were errors reading the project!");
       }
-      Err(())
+      report_errors(format, errors)
     }
   }
 }
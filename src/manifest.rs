@@ -0,0 +1,199 @@
+//! Reads `Project.mgl.toml`, e.g.
+//!
+//!   name = "game"
+//!   sources = ["src", "lib"]
+//!   output = "build"
+//!   target = "gms2"
+//!   default_room = "title"
+//!   assets = ["assets"]
+//!
+//! Every key is optional, paths are relative to the directory of the project file.
+
+use std::fs;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::export::{Target, TARGET_NAMES};
+use crate::utility::files::path_file_name;
+
+pub const PROJECT_FILE_NAME: &str = "Project.mgl.toml";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectManifest {
+  pub file: PathBuf,
+  pub directory: PathBuf,
+  pub name: String,
+  pub sources: Vec<PathBuf>,
+  pub output: PathBuf,
+  pub target: Target,
+  pub default_room: Option<ResourceName>,
+  pub assets: Vec<PathBuf>,
+
+  locations: HashMap<String, (usize, usize)>,
+}
+
+impl ProjectManifest {
+  pub fn default(file: &Path) -> ProjectManifest {
+    let file = file.canonicalize().unwrap_or(file.to_path_buf());
+    let directory = file.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    ProjectManifest {
+      name: path_file_name(&directory),
+      sources: vec![directory.join("src")],
+      output: directory.join("build"),
+      target: Target::Gms1,
+      default_room: None,
+      assets: vec![directory.clone()],
+      locations: HashMap::new(),
+      file,
+      directory,
+    }
+  }
+
+  pub fn from_file(file: &Path) -> TopResult<ProjectManifest> {
+    match fs::read_to_string(file) {
+      Ok(contents) => ProjectManifest::parse(file, &contents),
+      Err(e) => MglError::io(file, e).map_err(|e| vec![e])
+    }
+  }
+
+  /// Every key is checked, all of their errors are reported together.
+  pub fn parse(file: &Path, contents: &str) -> TopResult<ProjectManifest> {
+    let mut manifest = ProjectManifest::default(file);
+    manifest.locations = key_locations(contents);
+
+    let table = match contents.parse::<toml::Value>() {
+      Ok(toml::Value::Table(table)) => table,
+      Ok(_) => unreachable!(),
+
+      Err(e) => {
+        let (line, column) = e.line_col().map(|(l, c)| (l + 1, c + 1)).unwrap_or((1, 1));
+        let mut message = e.to_string();
+
+        if let Some(position) = message.find(" at line ") {
+          message.truncate(position);
+        }
+        return Err(vec![manifest.error_at("", ManifestErrorKind::Syntax(message), line, column)])
+      }
+    };
+
+    let mut keys = table.iter().collect::<Vec<_>>();
+    keys.sort_by_key(|(key, _)| manifest.locations.get(*key).cloned());
+
+    let mut errors = Vec::new();
+    for (key, value) in keys {
+      if let Err(e) = manifest.set(key, value) {
+        errors.push(e);
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(manifest)
+    } else {
+      Err(errors)
+    }
+  }
+
+  fn set(&mut self, key: &str, value: &toml::Value) -> Result<()> {
+    match key {
+      "name"         => self.name    = self.string(key, value)?,
+      "output"       => self.output  = self.path(key, value)?,
+      "sources"      => self.sources = self.paths(key, value)?,
+      "assets"       => self.assets  = self.paths(key, value)?,
+
+      "target" => {
+        let target = self.string(key, value)?;
+        self.target = match target.parse() {
+          Ok(target) => target,
+          Err(_) => {
+            let options = TARGET_NAMES.iter().map(|o| String::from(*o)).collect();
+            return Err(self.error(key, ManifestErrorKind::UnknownValue(target, options)))
+          }
+        }
+      }
+
+      "default_room" => {
+        let names  = self.string(key, value)?;
+        let names  = names.split("::").map(str::trim).collect::<Vec<_>>();
+        self.default_room = Some(ResourceName::new(&names).qualified("room"));
+      }
+
+      _ => {
+        return Err(self.error(key, ManifestErrorKind::UnknownKey))
+      }
+    }
+    Ok(())
+  }
+
+  /// An error pointing at the line where `key` was set.
+  pub fn error(&self, key: &str, kind: ManifestErrorKind) -> MglError {
+    let (line, column) = self.locations.get(key).cloned().unwrap_or((1, 1));
+    self.error_at(key, kind, line, column)
+  }
+
+  fn error_at(&self, key: &str, kind: ManifestErrorKind, line: usize, column: usize) -> MglError {
+    MglError::Manifest {
+      kind,
+      key: String::from(key),
+      path: self.file.clone(),
+      line,
+      column,
+    }
+  }
+
+  fn string(&self, key: &str, value: &toml::Value) -> Result<String> {
+    match value.as_str() {
+      Some(string) => Ok(String::from(string)),
+      None => Err(self.error(key, ManifestErrorKind::WrongType(String::from("string"))))
+    }
+  }
+
+  fn path(&self, key: &str, value: &toml::Value) -> Result<PathBuf> {
+    Ok(self.directory.join(self.string(key, value)?))
+  }
+
+  fn paths(&self, key: &str, value: &toml::Value) -> Result<Vec<PathBuf>> {
+    let wrong_type = || self.error(key, ManifestErrorKind::WrongType(String::from("array of strings")));
+
+    match value.as_array() {
+      Some(values) => {
+        let mut paths = Vec::new();
+        for value in values {
+          match value.as_str() {
+            Some(path) => paths.push(self.directory.join(path)),
+            None => return Err(wrong_type())
+          }
+        }
+        Ok(paths)
+      }
+      None => Err(wrong_type())
+    }
+  }
+}
+
+/// Where each top level key is set, `toml` doesn't keep track of it for us.
+fn key_locations(contents: &str) -> HashMap<String, (usize, usize)> {
+  let mut locations = HashMap::new();
+  let mut in_table  = false;
+
+  for (line, text) in contents.lines().enumerate() {
+    let trimmed = text.trim_start();
+    let column  = text.len() - trimmed.len() + 1;
+
+    if trimmed.starts_with('[') {
+      let table = trimmed.trim_matches(|c| c == '[' || c == ']' || c == ' ');
+      locations.entry(String::from(table)).or_insert((line + 1, column));
+      in_table = true;
+
+    } else if let Some(equals) = trimmed.find('=').filter(|_| !in_table) {
+      let key = trimmed[..equals].trim().trim_matches('"');
+
+      if !key.is_empty() && !key.starts_with('#') {
+        locations.entry(String::from(key)).or_insert((line + 1, column));
+      }
+    }
+  }
+  locations
+}
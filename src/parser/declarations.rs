@@ -31,14 +31,80 @@ pub fn parse_declaration(tk: Tokens) -> Option<Declaration> {
       Some(Declaration::Resource(parse_resource(tk, Sound)))
     }
 
+    Rule::font_declaration => {
+      Some(Declaration::Resource(parse_resource(tk, Font)))
+    }
+
+    Rule::path_declaration => {
+      Some(Declaration::Resource(parse_resource(tk, Path)))
+    }
+
+    Rule::background_declaration => {
+      Some(Declaration::Resource(parse_resource(tk, Background)))
+    }
+
     Rule::sprite_declaration => {
       Some(Declaration::Resource(parse_resource(tk, Sprite)))
     }
 
+    Rule::module_declaration => {
+      Some(Declaration::Module(parse_module(tk)))
+    }
+
+    Rule::import_declaration => {
+      Some(Declaration::Import(parse_import(tk)))
+    }
+
+    Rule::constant_declaration => {
+      Some(Declaration::Constant(parse_constant(tk)))
+    }
+
+    Rule::enum_declaration => {
+      Some(Declaration::Enum(parse_enum(tk)))
+    }
+
     _ => return None
   }
 }
 
+pub fn parse_module(tk: Tokens) -> ModuleDeclaration {
+  let path  = tk.into_inner().next().unwrap();
+  let names = match path.as_rule() {
+    Rule::resource => path.into_inner().map(|p| p.as_str()).collect::<Vec<_>>(),
+    _ => vec![path.as_str()],
+  };
+  ModuleDeclaration::new(&names)
+}
+
+pub fn parse_import(tk: Tokens) -> ImportDeclaration {
+  let mut parts = tk.into_inner();
+  let path  = parse_expression(parts.next().unwrap());
+  let alias = parts.next().map(|p| p.as_str());
+  ImportDeclaration::new(path, alias)
+}
+
+pub fn parse_constant(tk: Tokens) -> ConstantDeclaration {
+  let mut parts = tk.into_inner();
+  let name  = parts.next().unwrap().as_str();
+  let value = parse_expression(parts.next().unwrap());
+  ConstantDeclaration::new(name, value)
+}
+
+pub fn parse_enum(tk: Tokens) -> EnumDeclaration {
+  let mut parts = tk.into_inner();
+  let name = parts.next().unwrap().as_str();
+
+  let variants = parts.map(|variant| {
+    let mut parts = variant.into_inner();
+    let name  = parts.next().unwrap();
+    let value = parts.next().map(parse_expression);
+    let text  = String::from(name.clone().as_str());
+    EnumVariant::new(AstDebugInfo::new(text).with_info(name), value)
+  });
+
+  EnumDeclaration::new(name, &variants.collect::<Vec<_>>())
+}
+
 pub fn parse_function(tk: Tokens) -> FunctionDeclaration {
   let mut parts = tk.into_inner();
   let name = parts.next().unwrap().as_str();
@@ -63,6 +129,7 @@ pub fn parse_resource(tk: Tokens, kind: ResourceKind) -> ResourceDeclaration {
   let mut methods   = Vec::new();
   let mut keyvalues = Vec::new();
   let mut instances = Vec::new();
+  let mut constants = Vec::new();
 
   let mut parts = tk.into_inner();
   let name = parts.next().unwrap().as_str();
@@ -72,19 +139,21 @@ pub fn parse_resource(tk: Tokens, kind: ResourceKind) -> ResourceDeclaration {
       Rule::function_declaration => methods.push(parse_function(item)),
       Rule::instance_declaration => instances.push(parse_instance(item)),
       Rule::key_value            => keyvalues.push(parse_key_value(item)),
+      Rule::constant_declaration => constants.push(parse_constant(item)),
 
       _ => unreachable!()
     }
   }
 
-  ResourceDeclaration::new(kind, name, &keyvalues, &methods, &instances)
+  ResourceDeclaration::new(kind, name, &keyvalues, &methods, &instances).with_constants(&constants)
 }
 
 pub fn parse_key_value(tk: Tokens) -> KeyValue {
   let mut parts = tk.into_inner();
-  let key   = parse_key(parts.next().unwrap().into_inner());
-  let value = parse_expression(parts.next().unwrap());
-  KeyValue::new(key, value)
+  let key_tk = parts.next().unwrap();
+  let key    = IKey::new(parse_key(key_tk.clone().into_inner())).with_info(key_tk);
+  let value  = parse_expression(parts.next().unwrap());
+  KeyValue { key, value }
 }
 
 pub fn parse_key(mut tks: InnerTokens) -> Key {
@@ -12,13 +12,13 @@ pub fn parser_error(c: ParserContext, e: Error) -> MglError {
 
   if let ErrorVariant::ParsingError {positives, ..} = e.variant.clone() {
     if let LineColLocation::Pos((line, column)) = e.line_col.clone() {
-      println!("{}", line);
       let text = c.code.split("\n").nth(line-1).unwrap().clone();
 
       let err = |kind: ParserErrorKind| {
         MglError::Parser {
           error_kind: kind,
           verbose: c.verbose_errors,
+          file: c.file,
           text: String::from(text),
           line,
           column,
@@ -36,11 +36,11 @@ pub fn parser_error(c: ParserContext, e: Error) -> MglError {
       } else if positives == &[Rule::function_arguments_declaration] {
         return err(MissingArguments)
 
-      } else if positives == &[call, indexing, op, ternary_op, ternary_op_call] {
+      } else if positives == &[assignment_op, increment_op, call, indexing, word_op, ternary_op, ternary_op_call] {
         return err(IncompleteStatement)
 
       } else if positives == &[body] ||
-                positives == &[body, call, indexing, op, ternary_op] {
+                positives == &[body, call, indexing, word_op, ternary_op] {
         return err(MissingBody)
 
       } else if positives.contains(&return_statement) {
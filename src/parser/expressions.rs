@@ -24,6 +24,19 @@ pub fn parse_expression(top_tokens: Tokens) -> IExpr {
           Expression::resource(&names)
         }
 
+        Rule::array_literal => {
+          let values = tk.into_inner().map(parse_expression).collect::<Vec<_>>();
+          Expression::array(&values)
+        }
+
+        Rule::map_literal => {
+          let entries = tk.into_inner().map(|entry| {
+            let mut parts = entry.into_inner();
+            (parse_expression(parts.next().unwrap()), parse_expression(parts.next().unwrap()))
+          });
+          Expression::map(&entries.collect::<Vec<_>>())
+        }
+
         Rule::unary_op => {
           let mut parts = tk.into_inner();
           let operator = parts.next().unwrap().as_str();
@@ -31,14 +44,14 @@ pub fn parse_expression(top_tokens: Tokens) -> IExpr {
           Expression::unary_op(operator, operand)
         }
 
-        Rule::binary_op => {
+        Rule::binary_op | Rule::binary_op_call => {
           let mut parts = tk.into_inner();
           let op = parts.next().unwrap().as_str();
           let right = parse_expression(parts.next().unwrap());
           Expression::binary_op(op, expression.unwrap(), right)
         }
 
-        Rule::ternary_op => {
+        Rule::ternary_op | Rule::ternary_op_call => {
           let mut parts = tk.into_inner();
           let left  = parse_expression(parts.next().unwrap());
           let right = parse_expression(parts.next().unwrap());
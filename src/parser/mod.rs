@@ -7,6 +7,7 @@ pub mod expressions;
 pub mod statements;
 pub mod declarations;
 pub mod error;
+pub mod recovery;
 
 use crate::ast::Top;
 use crate::error::*;
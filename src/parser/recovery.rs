@@ -0,0 +1,236 @@
+//! Keeps parsing after a syntax error, so that a file reports all of them.
+//!
+//! The grammar has no way to recover by itself, so when a file doesn't parse
+//! it is split into declarations, each line that starts with a declaration
+//! keyword beginning a new one, and these are parsed one at a time. When a
+//! declaration fails, the statement holding the error is blanked out and the
+//! declaration is parsed again. Statements end at newlines and at the `}` of
+//! their block, if the error isn't in any statement the whole block is blanked.
+//!
+//! Blanking keeps every newline, so errors keep the lines and columns they
+//! have in the original file.
+
+use std::ops::Range;
+use crate::ast::Top;
+use crate::error::*;
+use crate::parser::{Ctx, parse_code};
+use crate::parser::context::ParserContext;
+
+const DECLARATION_KEYWORDS: &[&str] = &[
+  "module", "import", "use",
+  "object", "wrapper", "instance", "room", "sound", "font", "path",
+  "background", "sprite", "function", "const", "enum",
+];
+
+/// Every syntax error of the code along with the declarations that could be
+/// parsed around them.
+pub fn parse_code_recovering<'a, C: Ctx<'a>>(c: C) -> (Top, Vec<MglError>) {
+  let c = c.into();
+
+  let error = match reparse(&c, c.code) {
+    Ok(top) => return (top, Vec::new()),
+    Err(error) => error,
+  };
+
+  let mut declarations = Vec::new();
+  let mut errors       = Vec::new();
+
+  for chunk in split_declarations(c.code) {
+    let (top, mut chunk_errors) = parse_chunk(&c, &chunk);
+    declarations.extend(top.declarations);
+    errors.append(&mut chunk_errors);
+  }
+
+  // every declaration is fine on its own, but not where it was written
+  if errors.is_empty() {
+    errors.push(error);
+  }
+
+  (Top::new(&declarations), errors)
+}
+
+fn reparse(c: &ParserContext, code: &str) -> Result<Top> {
+  parse_code(ParserContext { code, file: c.file, verbose_errors: c.verbose_errors })
+}
+
+fn parse_chunk(c: &ParserContext, chunk: &Chunk) -> (Top, Vec<MglError>) {
+  let mut code   = blank(&blank(c.code, 0..chunk.range.start), chunk.range.end..c.code.len());
+  let mut errors = Vec::new();
+  let mut last_position = None;
+
+  loop {
+    match reparse(c, &code) {
+      Ok(top) => return (top, errors),
+
+      Err(mut error) => {
+        let position = error_position(&code, &error).min(chunk.range.end);
+        let region   = chunk.statement_at(&code, position);
+
+        // the same error again means the statement removed before wasn't the culprit
+        if last_position != Some(position) {
+          restore_text(&mut error, c.code);
+          errors.push(error);
+        }
+
+        if code[region.clone()].trim().is_empty() {
+          return (Top::new(&[]), errors)
+        }
+
+        last_position = Some(position);
+        code = blank(&code, region);
+      }
+    }
+  }
+}
+
+
+struct Block {
+  open: usize,
+  close: usize,
+  parent: Option<usize>,
+}
+
+/// A declaration and the blocks inside it, positions are byte offsets in the whole code.
+struct Chunk {
+  range: Range<usize>,
+  blocks: Vec<Block>,
+  newlines: Vec<(usize, Option<usize>)>,
+}
+
+impl Chunk {
+  fn new(code: &str, range: Range<usize>) -> Self {
+    let mut blocks   = Vec::<Block>::new();
+    let mut newlines = Vec::new();
+    let mut open     = Vec::new();
+    let mut chars    = code[range.clone()].char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+      let position = range.start + i;
+
+      match c {
+        '"' => {
+          for (_, c) in chars.by_ref() {
+            if c == '"' { break }
+          }
+        }
+
+        '/' if chars.peek().map(|&(_, c)| c) == Some('/') => {
+          while let Some(&(_, c)) = chars.peek() {
+            if c == '\n' { break }
+            chars.next();
+          }
+        }
+
+        '/' if chars.peek().map(|&(_, c)| c) == Some('*') => {
+          chars.next();
+          let mut previous = ' ';
+          for (_, c) in chars.by_ref() {
+            if previous == '*' && c == '/' { break }
+            previous = c;
+          }
+        }
+
+        '{' => {
+          blocks.push(Block { open: position, close: range.end, parent: open.last().cloned() });
+          open.push(blocks.len() - 1);
+        }
+
+        '}' => {
+          if let Some(block) = open.pop() {
+            blocks[block].close = position;
+          }
+        }
+
+        '\n' => newlines.push((position, open.last().cloned())),
+
+        _ => {}
+      }
+    }
+
+    Chunk { range, blocks, newlines }
+  }
+
+  /// The innermost block containing the position, its closing brace included.
+  fn block_at(&self, position: usize) -> Option<usize> {
+    self.blocks.iter()
+               .enumerate()
+               .rev()
+               .find(|(_, block)| block.open < position && position <= block.close)
+               .map(|(index, _)| index)
+  }
+
+  /// The statement to remove for an error at the position.
+  fn statement_at(&self, code: &str, mut position: usize) -> Range<usize> {
+    let mut block = self.block_at(position);
+
+    while let Some(index) = block {
+      let region = self.statement_in(index, position);
+
+      if !code[region.clone()].trim().is_empty() {
+        return region
+      }
+      position = self.blocks[index].open;
+      block    = self.blocks[index].parent;
+    }
+    self.range.clone()
+  }
+
+  fn statement_in(&self, index: usize, position: usize) -> Range<usize> {
+    let block        = &self.blocks[index];
+    let mut newlines = self.newlines.iter().filter(|(_, b)| *b == Some(index)).map(|(n, _)| *n);
+
+    let start = newlines.clone().rev().find(|n| *n < position).map(|n| n + 1).unwrap_or(block.open + 1);
+    let end   = newlines.find(|n| *n >= position).unwrap_or(block.close);
+    start..end
+  }
+}
+
+fn split_declarations(code: &str) -> Vec<Chunk> {
+  let mut starts = vec![0];
+  let mut offset = 0;
+
+  for line in code.split('\n') {
+    let starts_declaration = DECLARATION_KEYWORDS.iter().any(|keyword| {
+      line.starts_with(keyword) && line[keyword.len()..].starts_with([' ', '\t'])
+    });
+
+    if starts_declaration && offset > 0 {
+      starts.push(offset);
+    }
+    offset += line.len() + 1;
+  }
+
+  let ends = starts.iter().skip(1).cloned().chain(Some(code.len()));
+
+  starts.iter()
+        .zip(ends)
+        .filter(|&(&start, end)| !code[start..end].trim().is_empty())
+        .map(|(&start, end)| Chunk::new(code, start..end))
+        .collect()
+}
+
+/// Replaces the text in range with spaces, keeping newlines and byte offsets.
+fn blank(code: &str, range: Range<usize>) -> String {
+  code.char_indices().map(|(i, c)| {
+    if range.contains(&i) && c != '\n' { " ".repeat(c.len_utf8()) } else { c.to_string() }
+  }).collect()
+}
+
+fn error_position(code: &str, error: &MglError) -> usize {
+  if let &MglError::Parser { line, column, .. } = error {
+    let line_start = code.split('\n').take(line - 1).map(|l| l.len() + 1).sum::<usize>();
+    code[line_start..].char_indices()
+                      .nth(column - 1)
+                      .map(|(i, _)| line_start + i)
+                      .unwrap_or(code.len())
+  } else {
+    unreachable!()
+  }
+}
+
+/// Errors show the line as it was written, not as it was parsed.
+fn restore_text(error: &mut MglError, code: &str) {
+  if let MglError::Parser { text, line, .. } = error {
+    *text = String::from(code.split('\n').nth(*line - 1).unwrap_or(""));
+  }
+}
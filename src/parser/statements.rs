@@ -12,6 +12,26 @@ pub fn parse_statement(tk: Tokens) -> IStat {
         Statement::return_op(parse_expression(tk))
       }
 
+      Rule::break_statement => {
+        Statement::Break
+      }
+
+      Rule::continue_statement => {
+        Statement::Continue
+      }
+
+      Rule::exit_statement => {
+        Statement::Exit
+      }
+
+      Rule::repeat_statement => {
+        parse_repeat(tk)
+      }
+
+      Rule::do_statement => {
+        parse_do_until(tk)
+      }
+
       Rule::statement_call => {
         Statement::call(parse_expression(tk))
       }
@@ -32,6 +52,10 @@ pub fn parse_statement(tk: Tokens) -> IStat {
         parse_while(tk)
       }
 
+      Rule::switch_statement => {
+        parse_switch(tk)
+      }
+
       Rule::for_statement => {
         parse_for(tk)
       }
@@ -40,6 +64,22 @@ pub fn parse_statement(tk: Tokens) -> IStat {
         parse_assignment(tk)
       }
 
+      Rule::compound_assignment => {
+        parse_compound_assignment(tk)
+      }
+
+      Rule::prefix_increment => {
+        let mut parts = tk.into_inner();
+        let op = parts.next().unwrap().as_str();
+        Statement::prefix(op, parse_expression(parts.next().unwrap()))
+      }
+
+      Rule::postfix_increment => {
+        let mut parts = tk.into_inner();
+        let e = parse_expression(parts.next().unwrap());
+        Statement::postfix(e, parts.next().unwrap().as_str())
+      }
+
       Rule::var => {
         parse_var(tk)
       }
@@ -75,6 +115,53 @@ pub fn parse_while(tk: Tokens) -> Statement {
 }
 
 
+pub fn parse_repeat(tk: Tokens) -> Statement {
+  let mut parts = tk.into_inner();
+  let times = parse_expression(parts.next().unwrap());
+  let body  = parse_statement(parts.next().unwrap());
+  Statement::repeat(times, body)
+}
+
+
+pub fn parse_do_until(tk: Tokens) -> Statement {
+  let mut parts = tk.into_inner();
+  let body = parse_statement(parts.next().unwrap());
+  let cond = parse_expression(parts.next().unwrap());
+  Statement::do_until(body, cond)
+}
+
+
+pub fn parse_switch(tk: Tokens) -> Statement {
+  let mut parts = tk.into_inner();
+  let value   = parse_expression(parts.next().unwrap());
+  let mut cases   = Vec::new();
+  let mut default = None;
+
+  for part in parts {
+    match part.as_rule() {
+      Rule::switch_case    => cases.push(parse_switch_case(part)),
+      Rule::switch_default => default = part.into_inner().next().map(parse_statement),
+      _ => unreachable!()
+    }
+  }
+  Statement::switch(value, &cases, default)
+}
+
+
+pub fn parse_switch_case(tk: Tokens) -> SwitchCase {
+  let mut values = Vec::new();
+  let mut body   = None;
+
+  for part in tk.into_inner() {
+    match part.as_rule() {
+      Rule::expression => values.push(parse_expression(part)),
+      _ => body = Some(parse_statement(part)),
+    }
+  }
+  SwitchCase::new(&values, body.unwrap())
+}
+
+
 pub fn parse_for(tk: Tokens) -> Statement {
   let mut parts = tk.into_inner();
   let name  = parts.next().unwrap().as_str();
@@ -106,6 +193,15 @@ pub fn parse_assignment(tk: Tokens) -> Statement {
 }
 
 
+pub fn parse_compound_assignment(tk: Tokens) -> Statement {
+  let mut parts = tk.into_inner();
+  let left  = parse_expression(parts.next().unwrap());
+  let op    = parts.next().unwrap().as_str().trim_end_matches('=');
+  let right = parse_expression(parts.next().unwrap());
+  Statement::compound_assignment(op, left, right)
+}
+
+
 pub fn parse_var_assignment(tk: Tokens) -> VarDeclaration {
   let mut parts = tk.into_inner();
   let left  = parts.next().unwrap().as_str();
@@ -0,0 +1,75 @@
+use crate::ast::*;
+use crate::resources::resource_trait::*;
+use crate::resources::sprite::*;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Background {
+  pub image: Option<PathBuf>,
+  pub width: u64,
+  pub height: u64,
+  pub use_as_tileset: bool,
+  pub tile_width: u64,
+  pub tile_height: u64,
+  pub horizontal_offset: u64,
+  pub vertical_offset: u64,
+  pub horizontal_separation: u64,
+  pub vertical_separation: u64,
+
+  #[sub_resource]
+  pub texture: Texture,
+}
+
+impl Background {
+  /// GMS2 has no backgrounds, their images are sprites there.
+  pub fn sprite(&self) -> Sprite {
+    Sprite {
+      collision_kind: CollisionKind::Rectangle,
+      collision_tolerance: 0,
+      separate_masks: false,
+      width: self.width,
+      height: self.height,
+      origin: SpriteOrigin::default(),
+      frames: vec![Frame { data: self.image.clone() }],
+      bounding_box: BoundingBox {
+        mode: BoundingBoxMode::FullImage,
+        left: 0,
+        right: self.width.saturating_sub(1) as i64,
+        top: 0,
+        bottom: self.height.saturating_sub(1) as i64,
+      },
+      texture: self.texture,
+    }
+  }
+
+  /// How many tiles fit in the image, given the offsets and separations.
+  pub fn tile_count(&self) -> u64 {
+    let fit = |size: u64, offset: u64, tile: u64, separation: u64| {
+      (size.saturating_sub(offset) + separation) / (tile + separation).max(1)
+    };
+
+    let columns = fit(self.width,  self.horizontal_offset, self.tile_width,  self.horizontal_separation);
+    let rows    = fit(self.height, self.vertical_offset,   self.tile_height, self.vertical_separation);
+    columns * rows
+  }
+}
+
+impl ResourceDefault<ResourceDeclaration> for Background {
+  fn default(_resource: &ResourceDeclaration) -> Result<Self> {
+    Ok(
+      Background {
+        image: None,
+        width: 0,
+        height: 0,
+        use_as_tileset: false,
+        tile_width: 16,
+        tile_height: 16,
+        horizontal_offset: 0,
+        vertical_offset: 0,
+        horizontal_separation: 0,
+        vertical_separation: 0,
+        texture: Texture::default(),
+      },
+    )
+  }
+}
@@ -0,0 +1,74 @@
+use crate::resources::resource_trait::*;
+use crate::ast::*;
+
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Font {
+  pub name: String,
+  pub size: u64,
+  pub bold: bool,
+  pub italic: bool,
+  pub antialiasing: u8,
+  pub charset: u8,
+  pub texture_group: usize,
+
+  #[array_field]
+  #[sub_resource]
+  pub ranges: Vec<CharacterRange>,
+}
+
+/// The characters from `first` to `last`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct CharacterRange {
+  pub first: u64,
+  pub last: u64,
+}
+
+impl Font {
+  /// The ranges to export, a font without any has the printable ASCII characters.
+  pub fn character_ranges(&self) -> Vec<CharacterRange> {
+    if self.ranges.is_empty() {
+      vec![CharacterRange::default()]
+    } else {
+      self.ranges.clone()
+    }
+  }
+}
+
+impl ResourceDefault<ResourceDeclaration> for Font {
+  fn default(_resource: &ResourceDeclaration) -> Result<Self> {
+    Ok(
+      Font {
+        name: String::from("Arial"),
+        size: 12,
+        bold: false,
+        italic: false,
+        antialiasing: 3,
+        charset: 0,
+        texture_group: 0,
+        ranges: Vec::new(),
+      },
+    )
+  }
+
+  /// Either end of a range can be given first, so ranges are checked after both.
+  fn finish(&mut self, resource: &ResourceDeclaration) -> Result<()> {
+    // a range left to its default is never empty, so an empty one has a key
+    for KeyValue { key, .. } in resource.key_value_pairs.iter().filter(|kv| kv.key.content.name_of() == "ranges") {
+      let index = KeyInspector::get_array_index("ranges", key)?;
+
+      if self.ranges[index].first > self.ranges[index].last {
+        return MglError::invalid_field("ranges", InvalidFieldKind::EmptyRange(key.clone()))
+      }
+    }
+    Ok(())
+  }
+}
+
+impl Default for CharacterRange {
+  fn default() -> CharacterRange {
+    CharacterRange {
+      first: 32,
+      last: 127,
+    }
+  }
+}
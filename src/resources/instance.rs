@@ -19,14 +19,14 @@ impl ResourceDefault<InstanceDeclaration> for Instance {
   fn default(declaration: &InstanceDeclaration) -> Result<Self> {
     Ok(
       Instance {
-        object: declaration.object.clone(),
+        object: declaration.object.content_clone(),
         id: 0,
         x: 0,
         y: 0,
         scale: 1.0,
         rotation: 0.0,
         alpha: 1.0,
-        color: 0,
+        color: 0xffffff,
         creation_code: None,
       }
     )
@@ -2,8 +2,13 @@ pub mod project;
 pub mod sprite;
 pub mod script;
 pub mod sound;
+pub mod font;
+pub mod path;
+pub mod background;
 pub mod room;
 pub mod object;
 pub mod instance;
 pub mod resource_trait;
+pub mod references;
+pub mod wrapper;
 
@@ -7,19 +7,65 @@ use std::convert::TryFrom;
 #[derive(Debug, Clone, PartialEq, Resource)]
 pub struct Object {
   pub sprite: Option<ResourceName>,
+  pub mask: Option<ResourceName>,
+  pub visible: bool,
+  pub solid: bool,
+  pub depth: i64,
   pub persistent: bool,
 
+  #[sub_resource]
+  pub physics: Physics,
+
+  /// As written, where it's written is kept to report cycles.
+  #[ignore_field]
+  pub parent: Option<AstDebugInfo<ResourceName>>,
+
+  /// The objects a wrapper delegates to, as written, see `resources::wrapper`.
+  #[array_field]
+  pub wraps: Vec<Option<ResourceName>>,
+
   #[ignore_field]
   pub events: Vec<(Event, ResourceName)>,
 }
 
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Physics {
+  pub enabled: bool,
+  pub sensor: bool,
+
+  /// Left to the target when not given, a circle in GMS1 and a box in GMS2.
+  pub shape: Option<PhysicsShape>,
+
+  pub density: f64,
+  pub restitution: f64,
+  pub group: i64,
+  pub linear_damping: f64,
+  pub angular_damping: f64,
+  pub friction: f64,
+  pub awake: bool,
+  pub kinematic: bool,
+
+  #[array_field]
+  #[sub_resource]
+  pub shape_points: Vec<ShapePoint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhysicsShape {
+  Circle = 0,
+  Box,
+  Convex
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Resource)]
+pub struct ShapePoint {
+  pub x: f64,
+  pub y: f64,
+}
+
 impl Object {
   pub fn new(declaration: ResourceDeclaration) -> Result<Object> {
-    let mut object = Object {
-      sprite: None,
-      persistent: false,
-      events: Vec::new()
-    };
+    let mut object = Object::default();
 
     for KeyValue { key, value } in declaration.key_values() {
       match Event::try_from(key.clone()) {
@@ -27,18 +73,72 @@ impl Object {
           object.events.push((event, parse_event_value(value)?));
         }
 
-        Err(EventErrorKind::InvalidName) => {
+        Err(EventErrorKind::InvalidName(_)) if key.as_ref() == &Key::name("parent") => {
+          let parent = ResourceName::try_from(value.clone())?;
+          object.parent = Some(AstDebugInfo::with_location_of(parent, value));
+        }
+
+        Err(EventErrorKind::InvalidName(_)) if key.content.name_of() == "wraps" && declaration.kind != ResourceKind::Wrapper => {
+          return MglError::invalid_field("wraps", InvalidFieldKind::NotFound(key.clone()));
+        }
+
+        Err(EventErrorKind::InvalidName(_)) => {
+          object.parse_key_value(&declaration, key, value)?;
+        }
+
+        Err(EventErrorKind::Dot(_)) if key.content.name_of() == "physics" => {
           object.parse_key_value(&declaration, key, value)?;
         }
 
         Err(e) => {
-          return Err(MglError::Event { kind: e });
+          return MglError::event(e);
         }
       }
     }
 
     Ok(object)
   }
+
+  /// The objects a wrapper delegates to, in order.
+  pub fn wrapped(&self) -> Vec<ResourceName> {
+    self.wraps.iter().flatten().map(|name| name.qualified("object")).collect()
+  }
+}
+
+impl Default for Object {
+  fn default() -> Object {
+    Object {
+      sprite: None,
+      mask: None,
+      visible: true,
+      solid: false,
+      depth: 0,
+      persistent: false,
+      physics: Physics::default(),
+      parent: None,
+      wraps: Vec::new(),
+      events: Vec::new(),
+    }
+  }
+}
+
+impl Default for Physics {
+  fn default() -> Physics {
+    Physics {
+      enabled: false,
+      sensor: false,
+      shape: None,
+      density: 0.5,
+      restitution: 0.1,
+      group: 0,
+      linear_damping: 0.1,
+      angular_damping: 0.1,
+      friction: 0.2,
+      awake: true,
+      kinematic: false,
+      shape_points: Vec::new(),
+    }
+  }
 }
 
 fn parse_event_value(expr: &IExpr) -> Result<ResourceName> {
@@ -0,0 +1,44 @@
+use crate::resources::resource_trait::*;
+use crate::ast::*;
+
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Path {
+  pub closed: bool,
+  pub smooth: bool,
+  pub precision: u8,
+
+  #[array_field]
+  #[sub_resource]
+  pub points: Vec<PathPoint>,
+}
+
+/// A point of the path, `speed` is a percentage of the speed the path is started with.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct PathPoint {
+  pub x: f64,
+  pub y: f64,
+  pub speed: f64,
+}
+
+impl ResourceDefault<ResourceDeclaration> for Path {
+  fn default(_resource: &ResourceDeclaration) -> Result<Self> {
+    Ok(
+      Path {
+        closed: false,
+        smooth: false,
+        precision: 4,
+        points: Vec::new(),
+      },
+    )
+  }
+}
+
+impl Default for PathPoint {
+  fn default() -> PathPoint {
+    PathPoint {
+      x: 0.0,
+      y: 0.0,
+      speed: 100.0,
+    }
+  }
+}
@@ -1,30 +1,41 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::ast::*;
 use crate::error::*;
 use crate::compiler::file_reader::*;
+use crate::compiler::constants::{Constants, find_constant};
 use crate::resources::instance::Instance;
 use crate::resources::sprite::Sprite;
-use crate::resources::script::Script;
+use crate::resources::script::{Script, ScriptKind, SuperCall, method_call, super_call};
 use crate::resources::object::Object;
+use crate::resources::wrapper::*;
+use crate::event::Event;
 use crate::resources::sound::Sound;
+use crate::resources::font::Font;
+use crate::resources::path::Path;
+use crate::resources::background::Background;
 use crate::resources::room::{Room, InstanceItem, InstanceItems};
 use crate::resources::resource_trait::*;
+use crate::resources::references::*;
+use crate::utility::strings::closest;
 
 pub type Items<T> = Vec<Item<T>>;
 pub type Module = Vec<String>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Project {
-  pub sprites: Items<Sprite>,
-  pub scripts: Items<Script>,
-  pub objects: Items<Object>,
-  pub sounds:  Items<Sound>,
-  pub fonts:   Items<Font>,
-  pub rooms:   Items<Room>,
-  pub module:  Module,
+  pub sprites:     Items<Sprite>,
+  pub scripts:     Items<Script>,
+  pub objects:     Items<Object>,
+  pub sounds:      Items<Sound>,
+  pub fonts:       Items<Font>,
+  pub paths:       Items<Path>,
+  pub backgrounds: Items<Background>,
+  pub rooms:       Items<Room>,
+  pub module:      Module,
 
-  pub instances: HashMap<ResourceName, Instance>
+  pub instances:  HashMap<ResourceName, Instance>,
+  pub references: Vec<Reference>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,21 +44,20 @@ pub enum Item<F> {
   Group(String, Vec<Item<F>>)
 }
 
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct Font;
-
 impl Project {
   pub fn new(m: Module) -> Project {
     Project {
-      sprites:   Items::new(),
-      scripts:   Items::new(),
-      objects:   Items::new(),
-      sounds:    Items::new(),
-      fonts:     Items::new(),
-      rooms:     Items::new(),
-      instances: HashMap::new(),
-      module:    m,
+      sprites:     Items::new(),
+      scripts:     Items::new(),
+      objects:     Items::new(),
+      sounds:      Items::new(),
+      fonts:       Items::new(),
+      paths:       Items::new(),
+      backgrounds: Items::new(),
+      rooms:       Items::new(),
+      instances:   HashMap::new(),
+      references:  Vec::new(),
+      module:      m,
     }
   }
 
@@ -66,13 +76,16 @@ impl Project {
       }
       AstFileTree::Node(directory, files) |
       AstFileTree::Root(box AstFileTree::Node(directory, files)) => {
-        let mut instances = HashMap::new();
-        let mut sprites   = Vec::new();
-        let mut scripts   = Vec::new();
-        let mut objects   = Vec::new();
-        let mut sounds    = Vec::new();
-        let mut fonts     = Vec::new();
-        let mut rooms     = Vec::new();
+        let mut instances   = HashMap::new();
+        let mut references  = Vec::new();
+        let mut sprites     = Vec::new();
+        let mut scripts     = Vec::new();
+        let mut objects     = Vec::new();
+        let mut sounds      = Vec::new();
+        let mut fonts       = Vec::new();
+        let mut paths       = Vec::new();
+        let mut backgrounds = Vec::new();
+        let mut rooms       = Vec::new();
 
         let from_subtree = |t: &AstFileTree| {
           Project::from_ast_file_tree(t.clone(), m.clone())
@@ -82,11 +95,14 @@ impl Project {
           match subproject {
             Ok(mut subproject) => {
               instances.extend(subproject.instances);
+              references.append(&mut subproject.references);
               sprites.append(&mut subproject.sprites);
               scripts.append(&mut subproject.scripts);
               objects.append(&mut subproject.objects);
               sounds.append(&mut subproject.sounds);
               fonts.append(&mut subproject.fonts);
+              paths.append(&mut subproject.paths);
+              backgrounds.append(&mut subproject.backgrounds);
               rooms.append(&mut subproject.rooms);
             }
             Err(mut e) => {
@@ -95,22 +111,27 @@ impl Project {
           }
         }
         project.instances.extend(instances);
+        project.references.append(&mut references);
 
         if is_root {
-          project.sprites = sprites;
-          project.scripts = scripts;
-          project.objects = objects;
-          project.sounds  = sounds;
-          project.fonts   = fonts;
-          project.rooms   = rooms;
+          project.sprites     = sprites;
+          project.scripts     = scripts;
+          project.objects     = objects;
+          project.sounds      = sounds;
+          project.fonts       = fonts;
+          project.paths       = paths;
+          project.backgrounds = backgrounds;
+          project.rooms       = rooms;
 
         } else {
-          if !sprites.is_empty() { project.sprites.push(Item::Group(directory.clone(), sprites)); }
-          if !scripts.is_empty() { project.scripts.push(Item::Group(directory.clone(), scripts)); }
-          if !objects.is_empty() { project.objects.push(Item::Group(directory.clone(), objects)); }
-          if !sounds.is_empty()  { project.sounds.push(Item::Group(directory.clone(), sounds)); }
-          if !fonts.is_empty()   { project.fonts.push(Item::Group(directory.clone(), fonts)); }
-          if !rooms.is_empty()   { project.rooms.push(Item::Group(directory.clone(), rooms)); }
+          if !sprites.is_empty()     { project.sprites.push(Item::Group(directory.clone(), sprites)); }
+          if !scripts.is_empty()     { project.scripts.push(Item::Group(directory.clone(), scripts)); }
+          if !objects.is_empty()     { project.objects.push(Item::Group(directory.clone(), objects)); }
+          if !sounds.is_empty()      { project.sounds.push(Item::Group(directory.clone(), sounds)); }
+          if !fonts.is_empty()       { project.fonts.push(Item::Group(directory.clone(), fonts)); }
+          if !paths.is_empty()       { project.paths.push(Item::Group(directory.clone(), paths)); }
+          if !backgrounds.is_empty() { project.backgrounds.push(Item::Group(directory.clone(), backgrounds)); }
+          if !rooms.is_empty()       { project.rooms.push(Item::Group(directory.clone(), rooms)); }
         }
       }
       _ => unreachable!()
@@ -122,6 +143,128 @@ impl Project {
     }
   }
 
+  /// Replaces the instances that rooms refer to by name with the instances
+  /// themselves, see `find_instance` for how names are looked up.
+  pub fn link_instances(&mut self) -> TopResult<()> {
+    let mut errors = Vec::new();
+    link_rooms(&mut self.rooms, &self.instances, &mut errors);
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Checks that every sprite, background, object, script and path referred to by name exists.
+  pub fn validate_references(&self) -> TopResult<()> {
+    let mut names = HashMap::new();
+    names.insert("sprite",     item_names(&self.sprites));
+    names.insert("object",     item_names(&self.objects));
+    names.insert("script",     item_names(&self.scripts));
+    names.insert("path",       item_names(&self.paths));
+    names.insert("background", item_names(&self.backgrounds));
+
+    let mut errors = Vec::new();
+
+    for Reference { kind, name } in &self.references {
+      let known = &names[kind];
+
+      if !known.contains(&name.content) {
+        // the kind is the same for all of them, so it's left out of the comparison
+        let without_kind = |name: &ResourceName| name.names()[1..].join("::");
+        let known        = known.iter().map(without_kind).collect::<Vec<_>>();
+        let suggestion   = closest(&without_kind(&name.content), known.iter().map(String::as_str));
+
+        errors.push(MglError::UnknownReference {
+          kind: String::from(*kind),
+          name: name.clone(),
+          suggestion: suggestion.map(|s| format!("{}::{}", kind, s)),
+        });
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Checks that no object is its own ancestor.
+  pub fn check_inheritance(&self) -> TopResult<()> {
+    let mut errors = Vec::new();
+    find_parent_cycles(&self.objects, &mut errors);
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Gives every method the scripts of the other methods of its object, both
+  /// its own and inherited, and what `super()` calls in it. Checks that the
+  /// methods called with `self.f()` and `other.f()` exist, and that `super()`
+  /// is only called where there's something to call.
+  pub fn link_methods(&mut self) -> TopResult<()> {
+    let hierarchy = Hierarchy::new(&self.objects, &self.scripts);
+
+    let mut errors = Vec::new();
+    link_methods(&mut self.scripts, &hierarchy, &mut errors);
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Adds the delegate objects and the scripts wrappers are made of, and
+  /// replaces their events by ones forwarding to their delegates, see
+  /// `resources::wrapper`. Wrapped objects that don't exist are left out.
+  pub fn link_wrappers(&mut self) {
+    let hierarchy = Hierarchy::new(&self.objects, &self.scripts);
+    let scripts   = item_files(&self.scripts).into_iter().map(|(n, s)| (n.clone(), s.clone())).collect::<HashMap<_, _>>();
+    let wrappers  = item_files(&self.objects).into_iter().filter(|(_, object)| !object.wrapped().is_empty())
+                                             .map(|(n, o)| (n.clone(), o.clone()))
+                                             .collect::<Vec<_>>();
+
+    for (name, wrapper) in wrappers {
+      let group   = group_path(&self.objects, &name).unwrap();
+      let wrapped = wrapper.wrapped().into_iter().filter(|w| hierarchy.contains(w)).map(|w| {
+        Wrapped { events: hierarchy.events(&w), name: w }
+      }).collect::<Vec<_>>();
+
+      for w in &wrapped {
+        let (delegate_name, delegate) = delegate_object(&name, &wrapper, &w.name);
+        add_to_group(&mut self.objects, &group, Item::File(delegate_name, delegate));
+      }
+
+      for (method, (wrapped_object, script_name)) in hierarchy.forwarded_methods(&name) {
+        let (script_name, script) = forwarding_script(&name, &wrapped_object, &method, (&script_name, &scripts[&script_name]));
+        add_to_group(&mut self.scripts, &group, Item::File(script_name, script));
+      }
+
+      let mut events = Vec::new();
+      for (event, script_name, script) in event_scripts(&name, &wrapper, wrapper.parent.is_some(), &wrapped) {
+        add_to_group(&mut self.scripts, &group, Item::File(script_name.clone(), script));
+        events.push((event, script_name));
+      }
+      set_events(&mut self.objects, &name, events);
+    }
+  }
+
+  /// Gives every script the constants it can use, see `compiler::constants`.
+  pub fn attach_constants(&mut self, constants: &Constants) {
+    attach_constants(&mut self.scripts, constants);
+  }
+
+  /// Game Maker starts the game in the first room of the project.
+  pub fn set_first_room(&mut self, name: &ResourceName) -> bool {
+    move_to_front(&mut self.rooms, name)
+  }
+
   pub fn parse_declaration(&mut self, declaration: Declaration) -> Result<()> {
     match declaration {
       Declaration::Function(function) => {
@@ -135,6 +278,16 @@ impl Project {
       Declaration::Instance(instance) => {
         self.parse_instance(instance)?;
       }
+
+      Declaration::Module(module) => {
+        self.module = module.names;
+      }
+
+      // already resolved by `compiler::imports`
+      Declaration::Import(_) => {}
+
+      // evaluated by `compiler::constants`, and given to the scripts by `attach_constants`
+      Declaration::Constant(_) | Declaration::Enum(_) => {}
     }
     Ok(())
   }
@@ -147,10 +300,21 @@ impl Project {
     for method_ast in &resource.methods {
       let method      = Script::method(method_ast.clone(), resource_name.clone());
       let method_name = full_name_for("script", &method_ast.name, &sub_module);
+      self.references.append(&mut function_references(method_ast));
       self.scripts.push(Item::File(method_name, method));
     }
 
+    if resource.kind == ResourceKind::Object || resource.kind == ResourceKind::Wrapper {
+      self.references.append(&mut object_references(&resource));
+    }
+
+    if resource.kind == ResourceKind::Room {
+      self.references.append(&mut room_references(&resource));
+    }
+
     for instance_ast in &resource.instances {
+      self.references.append(&mut instance_references(instance_ast));
+
       let instance      = Instance::new(instance_ast.clone())?;
       let instance_name = full_name_for("instance", &instance_ast.name, &sub_module);
       self.instances.insert(instance_name, instance.clone());
@@ -167,28 +331,429 @@ impl Project {
     };
 
     match resource.kind {
-      ResourceKind::Object   => add_item!(objects, Object::new(resource)?),
-      ResourceKind::Wrapper  => add_item!(objects, Object::new(resource)?),
-      ResourceKind::Sprite   => add_item!(sprites, Sprite::new(resource)?),
-      ResourceKind::Sound    => add_item!(sounds,  Sound::new(resource)?),
-      ResourceKind::Room     => add_item!(rooms,   Room::new((resource, instances))?),
+      ResourceKind::Object     => add_item!(objects,     Object::new(resource)?),
+      ResourceKind::Wrapper    => add_item!(objects,     Object::new(resource)?),
+      ResourceKind::Sprite     => add_item!(sprites,     Sprite::new(resource)?),
+      ResourceKind::Sound      => add_item!(sounds,      Sound::new(resource)?),
+      ResourceKind::Font       => add_item!(fonts,       Font::new(resource)?),
+      ResourceKind::Path       => add_item!(paths,       Path::new(resource)?),
+      ResourceKind::Background => add_item!(backgrounds, Background::new(resource)?),
+      ResourceKind::Room       => add_item!(rooms,       Room::new((resource, instances))?),
     }
     Ok(())
   }
 
   pub fn parse_function(&mut self, function: FunctionDeclaration) -> Result<()> {
     let resource_name = full_name_for("script", &function.name, &self.module);
+    self.references.append(&mut function_references(&function));
     self.scripts.push(Item::File(resource_name, Script::global(function)));
     Ok(())
   }
 
   pub fn parse_instance(&mut self, declaration: InstanceDeclaration) -> Result<()>  {
     let instance_name = full_name_for("instance", &declaration.name, &self.module);
+    self.references.append(&mut instance_references(&declaration));
     self.instances.insert(instance_name, Instance::new(declaration)?);
     Ok(())
   }
 }
 
+fn link_rooms(rooms:     &mut Items<Room>,
+              instances: &HashMap<ResourceName, Instance>,
+              errors:    &mut Vec<MglError>) {
+  for item in rooms {
+    match item {
+      Item::File(room_name, room) => {
+        for instance_item in &mut room.instances {
+          if let InstanceItem::Unresolved(reference) = instance_item {
+            match find_instance(room_name, &reference.content, instances) {
+              Some(instance) => *instance_item = InstanceItem::Resolved(instance.clone()),
+              None => errors.push(MglError::UnknownInstance { name: reference.clone() }),
+            }
+          }
+        }
+      }
+      Item::Group(_, sub_items) => link_rooms(sub_items, instances, errors)
+    }
+  }
+}
+
+/// Names are relative to the room: `i` in `room::m::r` is the first of
+/// `instance::m::r::i`, `instance::m::i` and `instance::i` that exists.
+fn find_instance<'a>(room_name: &ResourceName,
+                     name:      &ResourceName,
+                     instances: &'a HashMap<ResourceName, Instance>) -> Option<&'a Instance> {
+  if name.top_module_is("instance") {
+    if let Some(instance) = instances.get(name) {
+      return Some(instance)
+    }
+  }
+
+  let room_module = room_name.names().split_off(1);
+  let names       = name.names();
+
+  for length in (0..=room_module.len()).rev() {
+    let mut candidate = vec!["instance"];
+    candidate.extend(room_module[..length].iter().map(String::as_str));
+    candidate.extend(names.iter().map(String::as_str));
+
+    if let Some(instance) = instances.get(&ResourceName::new(&candidate)) {
+      return Some(instance)
+    }
+  }
+  None
+}
+
+type Methods = HashMap<ResourceName, HashMap<String, ResourceName>>;
+
+/// The parents, wrapped objects, methods and events of every object, to find what each one inherits.
+struct Hierarchy {
+  parents: HashMap<ResourceName, ResourceName>,
+  wraps:   HashMap<ResourceName, Vec<ResourceName>>,
+  methods: Methods,
+  events:  HashMap<ResourceName, Vec<(Event, ResourceName)>>,
+}
+
+impl Hierarchy {
+  fn new(objects: &Items<Object>, scripts: &Items<Script>) -> Self {
+    let mut hierarchy = Hierarchy {
+      parents: HashMap::new(),
+      wraps:   HashMap::new(),
+      methods: HashMap::new(),
+      events:  HashMap::new(),
+    };
+
+    for (name, object) in item_files(objects) {
+      if let Some(parent) = &object.parent {
+        hierarchy.parents.insert(name.clone(), parent.content.qualified("object"));
+      }
+      hierarchy.wraps.insert(name.clone(), object.wrapped());
+      hierarchy.events.insert(name.clone(), object.events.clone());
+    }
+
+    for (script_name, script) in item_files(scripts) {
+      if let ScriptKind::Method(object) = &script.kind {
+        hierarchy.methods.entry(object.clone())
+                         .or_default()
+                         .insert(script.source.name.clone(), script_name.clone());
+      }
+    }
+    hierarchy
+  }
+
+  fn contains(&self, object: &ResourceName) -> bool {
+    self.events.contains_key(object)
+  }
+
+  /// The object, its parent, the parent of its parent and so on, stopping
+  /// before any object that was already seen if the parents form a cycle.
+  fn ancestors(&self, object: &ResourceName) -> Vec<ResourceName> {
+    let mut ancestors = vec![object.clone()];
+
+    while let Some(parent) = self.parents.get(ancestors.last().unwrap()) {
+      if ancestors.contains(parent) {
+        break
+      }
+      ancestors.push(parent.clone());
+    }
+    ancestors
+  }
+
+  /// The methods of an object along with the ones it inherits, the nearest
+  /// definition wins. Wrappers also have the methods they forward.
+  fn methods(&self, object: &ResourceName) -> HashMap<String, ResourceName> {
+    let mut methods = HashMap::new();
+
+    for ancestor in self.ancestors(object) {
+      for (name, script) in self.methods.get(&ancestor).into_iter().flatten() {
+        methods.entry(name.clone()).or_insert(script.clone());
+      }
+
+      for (name, _) in self.forwarded_methods(&ancestor) {
+        let script = method_name(&ancestor, &name);
+        methods.entry(name).or_insert(script);
+      }
+    }
+    methods
+  }
+
+  /// The methods of an object and its ancestors, without the ones wrappers forward.
+  fn inherited_methods(&self, object: &ResourceName) -> HashMap<String, ResourceName> {
+    let mut methods = HashMap::new();
+
+    for ancestor in self.ancestors(object) {
+      for (name, script) in self.methods.get(&ancestor).into_iter().flatten() {
+        methods.entry(name.clone()).or_insert(script.clone());
+      }
+    }
+    methods
+  }
+
+  /// The methods of the wrapped objects that the wrapper doesn't have, by
+  /// name, along with the object they come from and their script. The first
+  /// wrapped object having a method wins.
+  fn forwarded_methods(&self, wrapper: &ResourceName) -> BTreeMap<String, (ResourceName, ResourceName)> {
+    let own = self.inherited_methods(wrapper);
+    let mut forwarded = BTreeMap::new();
+
+    for wrapped in self.wraps.get(wrapper).into_iter().flatten() {
+      for (name, script) in self.inherited_methods(wrapped) {
+        if !own.contains_key(&name) {
+          forwarded.entry(name).or_insert((wrapped.clone(), script));
+        }
+      }
+    }
+    forwarded
+  }
+
+  /// The events of an object along with the ones it inherits, the nearest definition wins.
+  fn events(&self, object: &ResourceName) -> Vec<(Event, ResourceName)> {
+    let mut events = Vec::<(Event, ResourceName)>::new();
+
+    for ancestor in self.ancestors(object) {
+      for (event, script) in self.events.get(&ancestor).into_iter().flatten() {
+        if !events.iter().any(|(e, _)| e == event) {
+          events.push((event.clone(), script.clone()));
+        }
+      }
+    }
+    events
+  }
+
+  /// Events call the event of the parent, since Game Maker already knows which one
+  /// that is, other methods call the method they override.
+  fn super_call(&self, object: &ResourceName, script: &ResourceName, method: &str) -> Option<SuperCall> {
+    if !self.parents.contains_key(object) {
+      return None
+    }
+
+    if self.events.get(object).is_some_and(|events| events.iter().any(|(_, s)| s == script)) {
+      return Some(SuperCall::Event)
+    }
+
+    self.ancestors(object).iter().skip(1).find_map(|ancestor| {
+      self.methods.get(ancestor).and_then(|methods| methods.get(method)).cloned().map(SuperCall::Method)
+    })
+  }
+}
+
+/// Reports each cycle once, at the parent that closes it.
+fn find_parent_cycles(objects: &Items<Object>, errors: &mut Vec<MglError>) {
+  let files   = item_files(objects);
+  let parents = files.iter().filter_map(|(name, object)| {
+    object.parent.as_ref().map(|parent| (*name, parent))
+  }).collect::<HashMap<_, _>>();
+
+  let mut visited = HashSet::new();
+
+  for (name, _) in &files {
+    let mut path    = Vec::<ResourceName>::new();
+    let mut current = Some((*name).clone());
+
+    while let Some(object) = current {
+      if let Some(start) = path.iter().position(|name| name == &object) {
+        let parent = parents[path.last().unwrap()];
+        let cycle  = path[start..].iter().chain(Some(&object)).map(|name| name.names()[1..].join("::"));
+
+        errors.push(MglError::Inheritance {
+          kind: InheritanceErrorKind::Cycle(cycle.collect()),
+          expression: IExpr::with_location_of(Expression::from_resource_name(&parent.content), parent),
+        });
+        break
+      }
+
+      if !visited.insert(object.clone()) {
+        break
+      }
+
+      current = parents.get(&object).map(|parent| parent.content.qualified("object"));
+      path.push(object);
+    }
+  }
+}
+
+fn link_methods(scripts: &mut Items<Script>, hierarchy: &Hierarchy, errors: &mut Vec<MglError>) {
+  for item in scripts {
+    match item {
+      Item::File(script_name, script) => {
+        if let ScriptKind::Method(object) = &script.kind {
+          script.methods    = hierarchy.methods(object);
+          script.super_call = hierarchy.super_call(object, script_name, &script.source.name);
+        }
+        check_calls(script, errors);
+      }
+      Item::Group(_, sub_items) => link_methods(sub_items, hierarchy, errors)
+    }
+  }
+}
+
+fn check_calls(script: &Script, errors: &mut Vec<MglError>) {
+  for expression in script.source.body.content.expressions() {
+    check_expression_calls(expression, script, errors);
+  }
+}
+
+fn check_expression_calls(expression: &IExpr, script: &Script, errors: &mut Vec<MglError>) {
+  let methods = &script.methods;
+
+  match method_call(expression) {
+    Some(call) if script.kind != ScriptKind::Global && !methods.contains_key(call.method) => {
+      let suggestion = closest(call.method, methods.keys().map(String::as_str));
+
+      errors.push(MglError::UnknownReference {
+        kind: String::from("method"),
+        name: AstDebugInfo::with_location_of(ResourceName::Name(String::from(call.method)), call.location),
+        suggestion: suggestion.map(String::from),
+      });
+    }
+    _ => {}
+  }
+
+  match super_call(expression) {
+    Some((name, _)) if script.super_call.is_none() => {
+      errors.push(MglError::Inheritance {
+        kind: InheritanceErrorKind::SuperOutsideOverride,
+        expression: name.clone(),
+      });
+    }
+    _ => {}
+  }
+
+  for subexpression in expression.content.subexpressions() {
+    check_expression_calls(subexpression, script, errors);
+  }
+}
+
+fn attach_constants(scripts: &mut Items<Script>, constants: &Constants) {
+  for item in scripts {
+    match item {
+      Item::File(script_name, script) => {
+        // `script::m::f` was declared in `m`, and the method `script::m::object::f` in `m::object`
+        let mut module = script_name.names().split_off(1);
+        module.pop();
+
+        for (name, value) in constants {
+          script.constants.insert(name.clone(), value.clone());
+
+          // `const::m::State::Walk` can be `m::State::Walk`, `State::Walk` or `Walk` depending on the module
+          let names = name.names();
+          for start in 1..names.len() {
+            let short_name = ResourceName::new(&names[start..].iter().map(String::as_str).collect::<Vec<_>>());
+            let expression = Expression::from_resource_name(&short_name);
+
+            if find_constant(&expression, &module, constants).as_ref() == Some(name) {
+              script.constants.insert(short_name, value.clone());
+            }
+          }
+        }
+
+        // arguments, `var`s and `for` variables keep their names
+        for local in script.source.local_names() {
+          script.constants.remove(&ResourceName::Name(local.clone()));
+        }
+      }
+      Item::Group(_, sub_items) => attach_constants(sub_items, constants)
+    }
+  }
+}
+
+fn item_files<T>(items: &Items<T>) -> Vec<(&ResourceName, &T)> {
+  let mut files = Vec::new();
+
+  for item in items {
+    match item {
+      Item::File(name, file) => files.push((name, file)),
+      Item::Group(_, sub_items) => files.append(&mut item_files(sub_items)),
+    }
+  }
+  files
+}
+
+/// The directories of the group the item called `name` is in.
+fn group_path<T>(items: &Items<T>, name: &ResourceName) -> Option<Vec<String>> {
+  for item in items {
+    match item {
+      Item::File(file_name, _) if file_name == name => return Some(Vec::new()),
+      Item::File(..) => {}
+
+      Item::Group(directory, sub_items) => {
+        if let Some(mut path) = group_path(sub_items, name) {
+          path.insert(0, directory.clone());
+          return Some(path)
+        }
+      }
+    }
+  }
+  None
+}
+
+fn add_to_group<T>(items: &mut Items<T>, path: &[String], item: Item<T>) {
+  match path.split_first() {
+    None => items.push(item),
+
+    Some((directory, rest)) => {
+      let group = items.iter_mut().find_map(|item| match item {
+        Item::Group(name, sub_items) if name == directory => Some(sub_items),
+        _ => None
+      });
+
+      match group {
+        Some(sub_items) => add_to_group(sub_items, rest, item),
+
+        None => {
+          let mut sub_items = Vec::new();
+          add_to_group(&mut sub_items, rest, item);
+          items.push(Item::Group(directory.clone(), sub_items));
+        }
+      }
+    }
+  }
+}
+
+fn set_events(objects: &mut Items<Object>, name: &ResourceName, events: Vec<(Event, ResourceName)>) {
+  for item in objects {
+    match item {
+      Item::File(object_name, object) if object_name == name => {
+        object.events = events;
+        return
+      }
+      Item::File(..) => {}
+      Item::Group(_, sub_items) => set_events(sub_items, name, events.clone()),
+    }
+  }
+}
+
+fn item_names<T>(items: &Items<T>) -> Vec<ResourceName> {
+  let mut names = Vec::new();
+
+  for item in items {
+    match item {
+      Item::File(name, _) => names.push(name.clone()),
+      Item::Group(_, sub_items) => names.append(&mut item_names(sub_items)),
+    }
+  }
+  names
+}
+
+/// Moves the item called `name` to the front of its group, and that group to
+/// the front of its parent, and so on. Returns whether the item was found.
+fn move_to_front<T>(items: &mut Items<T>, name: &ResourceName) -> bool {
+  let position = items.iter_mut().position(|item| {
+    match item {
+      Item::File(file_name, _)  => file_name == name,
+      Item::Group(_, sub_items) => move_to_front(sub_items, name),
+    }
+  });
+
+  if let Some(position) = position {
+    let item = items.remove(position);
+    items.insert(0, item);
+    true
+  } else {
+    false
+  }
+}
+
 pub fn module_add(module: &Module, addition: String) -> Module {
   let mut new_module = module.clone();
   new_module.push(addition);
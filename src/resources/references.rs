@@ -0,0 +1,124 @@
+//! Resources refer to each other by name, these are collected while the
+//! project is built so that they can be checked once every name is known.
+
+use std::convert::TryFrom;
+
+use crate::ast::*;
+use crate::event::Event;
+use crate::resources::resource_trait::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+  /// The module of the resource kind being referred to: `sprite`, `background`, `object`, `script` or `path`
+  pub kind: &'static str,
+  pub name: AstDebugInfo<ResourceName>,
+}
+
+impl Reference {
+  pub fn new<T>(kind: &'static str, name: &ResourceName, location: &AstDebugInfo<T>) -> Self {
+    Reference {
+      kind,
+      name: AstDebugInfo::with_location_of(name.qualified(kind), location),
+    }
+  }
+
+  fn from_expression(kind: &'static str, expression: &IExpr) -> Option<Self> {
+    match expression.as_ref() {
+      Expression::Name(name)     => Some(Reference::new(kind, &ResourceName::Name(name.clone()), expression)),
+      Expression::Resource(name) => Some(Reference::new(kind, name, expression)),
+      _ => None
+    }
+  }
+}
+
+/// The sprite and mask of an object, its parent, the objects it wraps, the scripts of its events and the objects it collides with.
+pub fn object_references(declaration: &ResourceDeclaration) -> Vec<Reference> {
+  let mut references = Vec::new();
+
+  for KeyValue { key, value } in declaration.key_values() {
+    match Event::try_from(key.clone()) {
+      Ok(Event::Collision(object)) => {
+        references.push(Reference::new("object", &object, key.content.index_of().unwrap()));
+        references.extend(Reference::from_expression("script", value));
+      }
+
+      Ok(_) => {
+        references.extend(Reference::from_expression("script", value));
+      }
+
+      Err(_) if key.as_ref() == &Key::name("sprite") || key.as_ref() == &Key::name("mask") => {
+        references.extend(Reference::from_expression("sprite", value));
+      }
+
+      Err(_) if key.as_ref() == &Key::name("parent") || key.content.name_of() == "wraps" => {
+        references.extend(Reference::from_expression("object", value));
+      }
+
+      Err(_) => {}
+    }
+  }
+  references
+}
+
+/// The backgrounds of a room and of its tiles, the objects its views follow and its creation code.
+pub fn room_references(declaration: &ResourceDeclaration) -> Vec<Reference> {
+  let mut references = Vec::new();
+
+  for KeyValue { key, value } in declaration.key_values() {
+    if key.as_ref() == &Key::name("creation_code") {
+      references.extend(Reference::from_expression("script", value));
+    }
+
+    if let Key::Dot(box field, box Key::Name(sub_field)) = key.as_ref() {
+      match (field.name_of().as_str(), sub_field.as_str()) {
+        ("backgrounds", "background") | ("tiles", "background") => {
+          references.extend(Reference::from_expression("background", value));
+        }
+
+        ("views", "follow") => {
+          references.extend(Reference::from_expression("object", value));
+        }
+
+        _ => {}
+      }
+    }
+  }
+  references
+}
+
+/// The object of an instance and its creation code.
+pub fn instance_references(declaration: &InstanceDeclaration) -> Vec<Reference> {
+  let object = &declaration.object;
+  let mut references = vec![Reference::new("object", &object.content, object)];
+
+  for KeyValue { key, value } in declaration.key_values() {
+    if key.as_ref() == &Key::name("creation_code") {
+      references.extend(Reference::from_expression("script", value));
+    }
+  }
+  references
+}
+
+/// The paths a function names in its body, like `path_start(path::patrol, 4, path_action_stop, false)`.
+pub fn function_references(function: &FunctionDeclaration) -> Vec<Reference> {
+  let mut references = Vec::new();
+
+  for expression in function.body.content.expressions() {
+    expression_references(expression, &mut references);
+  }
+  references
+}
+
+fn expression_references(expression: &IExpr, references: &mut Vec<Reference>) {
+  match expression.as_ref() {
+    Expression::Resource(name) if name.top_module_is("path") => {
+      references.push(Reference::new("path", name, expression));
+    }
+
+    other => {
+      for subexpression in other.subexpressions() {
+        expression_references(subexpression, references);
+      }
+    }
+  }
+}
@@ -7,11 +7,16 @@ pub use crate::error::{Result, MglError, InvalidFieldKind};
 // Traits to implement
 
 pub trait Resource<T: ResourceAst>: Sized {
-  fn parse_key_value(&mut self, source: &T, key: &Key, value: &IExpr) -> Result<()>;
+  fn parse_key_value(&mut self, source: &T, key: &IKey, value: &IExpr) -> Result<()>;
 }
 
 pub trait ResourceDefault<T: ResourceAst>: Sized {
   fn default(source: &T) -> Result<Self>;
+
+  /// Called once every key is parsed, for what depends on more than one of them.
+  fn finish(&mut self, _source: &T) -> Result<()> {
+    Ok(())
+  }
 }
 
 // Helper functions
@@ -58,6 +63,7 @@ impl<S: ResourceDefault<T> + Resource<T>, T: ResourceAst> ResourceCreate<T> for
     for KeyValue { key, value } in source.key_values() {
       resource.parse_key_value(&source, key, value)?;
     }
+    resource.finish(&source)?;
     Ok(resource)
   }
 }
@@ -67,23 +73,24 @@ impl<S: ResourceDefault<T> + Resource<T>, T: ResourceAst> ResourceCreate<T> for
 pub struct KeyInspector;
 
 impl KeyInspector {
-  pub fn assert_field_has_no_index(field: &str, key: &Key) -> Result<()> {
-    match key {
+  pub fn assert_field_has_no_index(field: &str, key: &IKey) -> Result<()> {
+    match key.as_ref() {
       &Key::Name(_) => Ok(()),
       _ => MglError::invalid_field(field, InvalidFieldKind::NotSimple(key.clone()))
     }
   }
 
-  pub fn get_array_index(field: &str, key: &Key) -> Result<usize> {
-    match key.leftmost_index_of() {
+  pub fn get_array_index(field: &str, key: &IKey) -> Result<usize> {
+    match key.content.leftmost_index_of() {
       Some(a) => Ok(parse_field_default(&a)?),
       _ => MglError::invalid_field(field, InvalidFieldKind::NotArray(key.clone()))
     }
   }
 
-  pub fn get_sub_field_key(field: &str, key: &Key) -> Result<Key> {
-    match key {
-      Key::Dot(_, box sf) => Ok(sf.clone()),
+  /// The key of the sub field, found at the same place as the whole key.
+  pub fn get_sub_field_key(field: &str, key: &IKey) -> Result<IKey> {
+    match key.as_ref() {
+      Key::Dot(_, box sf) => Ok(key.clone().with_content(sf.clone())),
       _ => MglError::invalid_field(field, InvalidFieldKind::NotSubResource(key.clone()))
     }
   }
@@ -1,10 +1,13 @@
 use crate::ast::*;
 use crate::resources::instance::*;
 use crate::resources::resource_trait::*;
-use std::path::PathBuf;
 
 pub type InstanceItems = Vec<InstanceItem>;
 
+/// Rooms always have this many backgrounds and views, unused ones are invisible.
+pub const ROOM_BACKGROUNDS: usize = 8;
+pub const ROOM_VIEWS:       usize = 8;
+
 #[derive(Debug, Clone, PartialEq, Resource)]
 pub struct Room {
   pub width: u64,
@@ -15,16 +18,80 @@ pub struct Room {
   pub clear_view_background: bool,
   pub clear_display_buffer: bool,
   pub color: u32,
-  pub tiled: Option<PathBuf>,
+  pub creation_code: Option<ResourceName>,
 
   #[array_field]
   pub instances: InstanceItems,
+
+  #[array_field]
+  #[sub_resource]
+  pub backgrounds: Vec<RoomBackground>,
+
+  #[array_field]
+  #[sub_resource]
+  pub tiles: Vec<Tile>,
+
+  #[array_field]
+  #[sub_resource]
+  pub views: Vec<View>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// A background drawn behind the room, or in front of it if `foreground` is set.
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct RoomBackground {
+  pub background: Option<ResourceName>,
+  pub visible: bool,
+  pub foreground: bool,
+  pub x: i64,
+  pub y: i64,
+  pub htiled: bool,
+  pub vtiled: bool,
+  pub hspeed: i64,
+  pub vspeed: i64,
+  pub stretch: bool,
+}
+
+/// The region of a tileset from `left`, `top` of size `width`, `height` placed at `x`, `y`.
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Tile {
+  pub background: Option<ResourceName>,
+  pub x: i64,
+  pub y: i64,
+  pub left: u64,
+  pub top: u64,
+  pub width: u64,
+  pub height: u64,
+  pub depth: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum InstanceItem {
   Resolved(Instance),
-  Unresolved(ResourceName),
+  Unresolved(AstDebugInfo<ResourceName>),
+  /// A gap left by setting `instances[n]` past the end of the list.
+  #[default]
+  Empty,
+}
+
+/// Shows the region of the room at `view_*` in the region of the window at `port_*`,
+/// scrolling to keep `follow` inside the borders. A speed of -1 has no limit.
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct View {
+  pub visible: bool,
+  pub view_x: i64,
+  pub view_y: i64,
+  pub view_w: u64,
+  pub view_h: u64,
+  pub port_x: i64,
+  pub port_y: i64,
+  pub port_w: u64,
+  pub port_h: u64,
+  pub angle: f64,
+  pub hborder: u64,
+  pub vborder: u64,
+  pub hspeed: i64,
+  pub vspeed: i64,
+  pub follow: Option<ResourceName>,
 }
 
 impl ResourceDefault<(ResourceDeclaration, InstanceItems)> for Room {
@@ -39,16 +106,104 @@ impl ResourceDefault<(ResourceDeclaration, InstanceItems)> for Room {
         clear_view_background: false,
         clear_display_buffer: false,
         color: 0xc0c0c0,
-        tiled: None,
+        creation_code: None,
         instances: instances.clone(),
+        backgrounds: Vec::new(),
+        tiles: Vec::new(),
+        views: Vec::new(),
       },
     )
   }
+
+  /// Checks the indices of backgrounds and views, that every tile has a background,
+  /// and points unresolved instances at their key in the room.
+  fn finish(&mut self, (resource, _): &(ResourceDeclaration, InstanceItems)) -> Result<()> {
+    let keys = resource.key_value_pairs.iter().map(|kv| &kv.key).collect::<Vec<_>>();
+
+    for (field, size) in &[("backgrounds", ROOM_BACKGROUNDS), ("views", ROOM_VIEWS)] {
+      for key in keys.iter().filter(|key| key.content.name_of() == *field) {
+        if KeyInspector::get_array_index(field, key)? >= *size {
+          return MglError::invalid_field(field, InvalidFieldKind::OutOfRange((*key).clone(), *size))
+        }
+      }
+    }
+
+    // an instance that can't be found is reported at its key in the room
+    for key in keys.iter().filter(|key| key.content.name_of() == "instances") {
+      let index = KeyInspector::get_array_index("instances", key)?;
+
+      if let InstanceItem::Unresolved(name) = &self.instances[index] {
+        self.instances[index] = InstanceItem::Unresolved(AstDebugInfo::with_location_of(*name.content.clone(), *key));
+      }
+    }
+
+    let tile_keys = keys.iter().filter(|key| key.content.name_of() == "tiles").map(|key| {
+      Ok((KeyInspector::get_array_index("tiles", key)?, *key))
+    }).collect::<Result<Vec<_>>>()?;
+
+    for (index, tile) in self.tiles.iter().enumerate() {
+      // a tile without keys of its own was added by one further in the list
+      let key = tile_keys.iter().find(|(i, _)| *i == index).or_else(|| tile_keys.iter().find(|(i, _)| *i > index));
+
+      if let (None, Some((_, key))) = (&tile.background, key) {
+        return MglError::invalid_field("tiles", InvalidFieldKind::MissingSubField((*key).clone(), String::from("background")))
+      }
+    }
+    Ok(())
+  }
 }
 
-impl Default for InstanceItem {
+
+impl Default for RoomBackground {
+  fn default() -> Self {
+    RoomBackground {
+      background: None,
+      visible: true,
+      foreground: false,
+      x: 0,
+      y: 0,
+      htiled: true,
+      vtiled: true,
+      hspeed: 0,
+      vspeed: 0,
+      stretch: false,
+    }
+  }
+}
+
+impl Default for Tile {
   fn default() -> Self {
-    InstanceItem::Unresolved(ResourceName::Name(String::from("")))
+    Tile {
+      background: None,
+      x: 0,
+      y: 0,
+      left: 0,
+      top: 0,
+      width: 16,
+      height: 16,
+      depth: 1000000,
+    }
   }
 }
 
+impl Default for View {
+  fn default() -> Self {
+    View {
+      visible: true,
+      view_x: 0,
+      view_y: 0,
+      view_w: 1024,
+      view_h: 768,
+      port_x: 0,
+      port_y: 0,
+      port_w: 1024,
+      port_h: 768,
+      angle: 0.0,
+      hborder: 32,
+      vborder: 32,
+      hspeed: -1,
+      vspeed: -1,
+      follow: None,
+    }
+  }
+}
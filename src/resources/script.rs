@@ -1,9 +1,31 @@
+use std::collections::HashMap;
+
 use crate::ast::*;
+use crate::compiler::constants::Constants;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Script {
   pub source: FunctionDeclaration,
   pub kind: ScriptKind,
+
+  /// The constants the script can use, by the name it would use them with.
+  pub constants: Constants,
+
+  /// For methods, the scripts of every method of their object, by method name.
+  /// Methods inherited from parent objects are included unless overridden.
+  pub methods: HashMap<String, ResourceName>,
+
+  /// What `super()` calls, if the script can call it.
+  pub super_call: Option<SuperCall>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SuperCall {
+  /// The same event of the parent object, through `event_inherited()`.
+  Event,
+
+  /// The script of the overridden method.
+  Method(ResourceName),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,15 +38,61 @@ impl Script {
   pub fn global(source: FunctionDeclaration) -> Script {
     Script {
       source,
-      kind: ScriptKind::Global
+      kind: ScriptKind::Global,
+      constants: Constants::new(),
+      methods: HashMap::new(),
+      super_call: None,
     }
   }
 
   pub fn method(source: FunctionDeclaration, name: ResourceName) -> Script {
     Script {
       source,
-      kind: ScriptKind::Method(name)
+      kind: ScriptKind::Method(name),
+      constants: Constants::new(),
+      methods: HashMap::new(),
+      super_call: None,
     }
   }
 }
 
+
+/// `self.f(...)` or `other.f(...)`, which call the method `f` of the object.
+/// The object of `other` is only known when the game runs, so `f` is always
+/// looked up in the object the call is written in: `other` is expected to be
+/// one of its instances, or of its children.
+pub struct MethodCall<'a> {
+  pub instance: &'a str,
+  pub method: &'a str,
+  pub arguments: &'a Vec<IExpr>,
+
+  /// Where the method name is.
+  pub location: &'a IExpr,
+}
+
+pub fn method_call(expression: &IExpr) -> Option<MethodCall<'_>> {
+  let (instance, call) = match expression.as_ref() {
+    Expression::BinaryOp(BinaryOp::Dot, instance, call) => (instance, call),
+    _ => return None
+  };
+
+  match (instance.as_ref(), call.as_ref()) {
+    (Expression::Name(instance), Expression::Call(method, arguments)) if instance == "self" || instance == "other" => {
+      match method.as_ref() {
+        Expression::Name(name) => Some(MethodCall { instance, method: name, arguments, location: method }),
+        _ => None
+      }
+    }
+    _ => None
+  }
+}
+
+/// The name and arguments of `super(...)`.
+pub fn super_call(expression: &IExpr) -> Option<(&IExpr, &Vec<IExpr>)> {
+  match expression.as_ref() {
+    Expression::Call(function, arguments) if function.as_ref() == &Expression::Name(String::from("super")) => {
+      Some((function, arguments))
+    }
+    _ => None
+  }
+}
@@ -69,6 +69,17 @@ pub enum CollisionKind {
   Rectangle
 }
 
+impl Sprite {
+  /// The origin in pixels, a centered origin ignores `x` and `y`.
+  pub fn origin_point(&self) -> (i64, i64) {
+    if self.origin.center {
+      ((self.width / 2) as i64, (self.height / 2) as i64)
+    } else {
+      (self.origin.x, self.origin.y)
+    }
+  }
+}
+
 impl ResourceDefault<ResourceDeclaration> for Sprite {
   fn default(_resource: &ResourceDeclaration) -> Result<Sprite> {
     Ok(
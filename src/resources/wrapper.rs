@@ -0,0 +1,157 @@
+//! Game Maker objects have a single parent, wrappers emulate having more:
+//!
+//!   wrapper player {
+//!     wraps[0]: body
+//!     wraps[1]: health
+//!   }
+//!
+//! Every instance of `player` creates an instance of a delegate object for
+//! each object it wraps, `player::body` and `player::health`, which hold the
+//! variables of the wrapped object. The methods of `body` and `health` that
+//! `player` doesn't have become scripts of `player` running them inside the
+//! delegate, and so do their events. Inside them `other` is the wrapper.
+
+use crate::ast::*;
+use crate::event::Event;
+use crate::compiler::export::{asset_name, asset_name_in};
+use crate::resources::object::Object;
+use crate::resources::script::Script;
+
+/// A wrapped object along with the events it has, inherited ones included.
+pub struct Wrapped {
+  pub name: ResourceName,
+  pub events: Vec<(Event, ResourceName)>,
+}
+
+/// The object holding the variables of a wrapped object, it does nothing by itself.
+pub fn delegate_object(wrapper_name: &ResourceName, wrapper: &Object, wrapped: &ResourceName) -> (ResourceName, Object) {
+  let mut names = wrapper_name.names();
+  names.extend(wrapped.names().split_off(1));
+
+  let delegate = Object {
+    visible: false,
+    persistent: wrapper.persistent,
+    ..Object::default()
+  };
+  (ResourceName::new(&names.iter().map(String::as_str).collect::<Vec<_>>()), delegate)
+}
+
+/// The method `method` of the wrapper, calling `script` of a wrapped object in its delegate.
+pub fn forwarding_script(wrapper_name: &ResourceName,
+                         wrapped:      &ResourceName,
+                         method:       &str,
+                         (script_name, script): (&ResourceName, &Script)) -> (ResourceName, Script) {
+  let arguments = script.source.args.iter().map(|argument| name(argument)).collect::<Vec<_>>();
+  let result    = "__mgl_result";
+
+  let statements = vec![
+    statement(Statement::var(&[VarDeclaration::name(result)])),
+    statement(Statement::with(name(&delegate_variable(wrapped)), body(vec![
+      statement(Statement::assignment(name(result), call(&asset_name(script_name), &arguments))),
+    ]))),
+    statement(Statement::return_op(name(result))),
+  ];
+
+  let arguments = script.source.args.iter().map(String::as_str).collect::<Vec<_>>();
+  generated_script(wrapper_name, FunctionDeclaration::new(method, &arguments, body(statements)))
+}
+
+/// The scripts the wrapper runs for each event, every event of the wrapper or of
+/// the objects it wraps gets one. The delegates are created before anything
+/// else, and destroyed after everything else.
+pub fn event_scripts(wrapper_name: &ResourceName,
+                     wrapper:      &Object,
+                     has_parent:   bool,
+                     wrapped:      &[Wrapped]) -> Vec<(Event, ResourceName, Script)> {
+  let mut events = vec![Event::Create, Event::Destroy];
+
+  for (event, _) in wrapper.events.iter().chain(wrapped.iter().flat_map(|w| &w.events)) {
+    if !events.contains(event) {
+      events.push(event.clone());
+    }
+  }
+
+  events.into_iter().map(|event| {
+    let own = wrapper.events.iter().find(|(own, _)| own == &event).map(|(_, script)| {
+      call_statement(&asset_name(script), &[])
+    });
+
+    // without its own script the wrapper would hide the event of its parent
+    let own = own.or_else(|| if has_parent { Some(call_statement("event_inherited", &[])) } else { None });
+
+    let forwards = wrapped.iter().filter_map(|w| {
+      let script = w.events.iter().find(|(e, _)| e == &event).map(|(_, script)| {
+        call_statement(&asset_name(script), &[])
+      });
+
+      let statements = match (&event, script) {
+        (Event::Destroy, script) => script.into_iter().chain(Some(call_statement("instance_destroy", &[]))).collect(),
+        (_, Some(script)) => vec![script],
+        (_, None) => return None,
+      };
+      Some(statement(Statement::with(name(&delegate_variable(&w.name)), body(statements))))
+    }).collect::<Vec<_>>();
+
+    let statements = match &event {
+      Event::Create => {
+        let creations = wrapped.iter().map(|w| {
+          let (delegate_name, _) = delegate_object(wrapper_name, wrapper, &w.name);
+          let creation = call("instance_create", &[name("x"), name("y"), name(&asset_name(&delegate_name))]);
+          statement(Statement::assignment(name(&delegate_variable(&w.name)), creation))
+        }).collect::<Vec<_>>();
+
+        creations.into_iter().chain(forwards).chain(own).collect()
+      }
+      Event::Destroy => own.into_iter().chain(forwards).collect(),
+      _ => forwards.into_iter().chain(own).collect(),
+    };
+
+    let function = FunctionDeclaration::new(&event_script_name(&event), &[], body(statements));
+    let (name, script) = generated_script(wrapper_name, function);
+    (event, name, script)
+  }).collect()
+}
+
+/// The instance variable of the wrapper holding the delegate of `wrapped`.
+fn delegate_variable(wrapped: &ResourceName) -> String {
+  format!("__mgl_delegate_{}", wrapped.names()[1..].join("__"))
+}
+
+fn event_script_name(event: &Event) -> String {
+  match event {
+    Event::Collision(object) => format!("__mgl_collision_{}", asset_name_in("object", object)),
+    _ => format!("__mgl_event_{}_{}", event.event_type(), event.event_number()),
+  }
+}
+
+/// Methods of `object::m::o` are called `script::m::o::f`.
+pub fn method_name(object: &ResourceName, method: &str) -> ResourceName {
+  let mut names = object.names();
+  names[0] = String::from("script");
+  names.push(String::from(method));
+  ResourceName::new(&names.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+fn generated_script(wrapper_name: &ResourceName, function: FunctionDeclaration) -> (ResourceName, Script) {
+  (method_name(wrapper_name, &function.name), Script::method(function, wrapper_name.clone()))
+}
+
+fn name(name: &str) -> IExpr {
+  IExpr::new(Expression::name(name))
+}
+
+fn call(function: &str, arguments: &[IExpr]) -> IExpr {
+  IExpr::new(Expression::call(name(function), arguments))
+}
+
+fn statement(statement: Statement) -> IStat {
+  IStat::new(statement)
+}
+
+fn call_statement(function: &str, arguments: &[IExpr]) -> IStat {
+  statement(Statement::call(call(function, arguments)))
+}
+
+fn body(statements: Vec<IStat>) -> IStat {
+  statement(Statement::body(&statements))
+}
@@ -50,7 +50,7 @@ fn test_ast_convert_instance_item() {
   let ok  = InstanceItem::try_from(expr("name"));
   let err = InstanceItem::try_from(expr("0"));
 
-  assert_eq!(ok,  Ok(InstanceItem::Unresolved(ResourceName::new(&["name"]))));
+  assert_eq!(ok,  Ok(InstanceItem::Unresolved(AstDebugInfo::new(ResourceName::new(&["name"])))));
   assert_eq!(err, MglError::convert_expression(expr("0"), "ResourceName"));
 }
 
@@ -63,7 +63,7 @@ fn test_ast_convert_collision_kind() {
 
   assert_eq!(ok1,  Ok(CollisionKind::Precise));
   assert_eq!(ok2,  Ok(CollisionKind::Rectangle));
-  assert_eq!(err1, MglError::unknown_option("unknown", &["precise","rectangle"]));
+  assert_eq!(err1, MglError::unknown_option_at(&expr("\"unknown\""), "unknown", &["precise","rectangle"]));
   assert_eq!(err2, MglError::convert_expression(expr("0"), "String"));
 }
 
@@ -78,7 +78,7 @@ fn test_ast_convert_bounding_box_mode() {
   assert_eq!(ok1,  Ok(BoundingBoxMode::Automatic));
   assert_eq!(ok2,  Ok(BoundingBoxMode::FullImage));
   assert_eq!(ok3,  Ok(BoundingBoxMode::Manual));
-  assert_eq!(err1, MglError::unknown_option("unknown", &["automatic","full_image","manual"]));
+  assert_eq!(err1, MglError::unknown_option_at(&expr("\"unknown\""), "unknown", &["automatic","full_image","manual"]));
   assert_eq!(err2, MglError::convert_expression(expr("0"), "String"));
 }
 
@@ -97,7 +97,7 @@ fn test_ast_convert_sound_kind() {
   assert_eq!(ok2,  Ok(SoundKind::Background));
   assert_eq!(ok3,  Ok(SoundKind::ThreeDimensional));
   assert_eq!(ok4,  Ok(SoundKind::ExternalPlayer));
-  assert_eq!(err1, MglError::unknown_option("unknown", &options));
+  assert_eq!(err1, MglError::unknown_option_at(&expr("\"unknown\""), "unknown", &options));
   assert_eq!(err2, MglError::convert_expression(expr("0"), "String"));
 }
 
@@ -120,6 +120,9 @@ fn test_ast_convert_number() {
   test_ast_convert_number!(f32, 0.0);
   test_ast_convert_number!(f64, 0.0);
   test_ast_convert_number!(usize, 0);
+
+  assert_eq!(i64::try_from(expr("-10")), Ok(-10));
+  assert_eq!(f64::try_from(expr("-0.5")), Ok(-0.5));
 }
 
 #[test]
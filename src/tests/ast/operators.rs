@@ -19,8 +19,18 @@ fn test_ast_operator_names() {
   assert_operator_name!(BinaryOp, "<=");
   assert_operator_name!(BinaryOp, "==");
   assert_operator_name!(BinaryOp, "!=");
+  assert_operator_name!(BinaryOp, "%");
+  assert_operator_name!(BinaryOp, "div");
+  assert_operator_name!(BinaryOp, "&");
+  assert_operator_name!(BinaryOp, "|");
+  assert_operator_name!(BinaryOp, "^");
+  assert_operator_name!(BinaryOp, "<<");
+  assert_operator_name!(BinaryOp, ">>");
+  assert_operator_name!(BinaryOp, "^^");
   assert_operator_name!(UnaryOp,  "-");
   assert_operator_name!(UnaryOp,  "!");
+  assert_operator_name!(IncrementOp, "++");
+  assert_operator_name!(IncrementOp, "--");
   assert_operator_name!(Accessor, "");
   assert_operator_name!(Accessor, "|");
   assert_operator_name!(Accessor, "?");
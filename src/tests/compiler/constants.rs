@@ -0,0 +1,236 @@
+use crate::tests::utility::*;
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::constants::*;
+use crate::compiler::imports::resolve_imports;
+use crate::compiler::script::*;
+use crate::resources::project::*;
+
+fn constants(files: &[&str]) -> Constants {
+  evaluate_constants(&resolve_imports(file_tree(files)).unwrap()).unwrap()
+}
+
+fn constant(names: &[&str]) -> ResourceName {
+  ResourceName::new(&[&["const"], names].concat())
+}
+
+/// Negative values are written in parentheses.
+fn negative(n: &str) -> IExpr {
+  IExpr::new(Expression::parentheses(IExpr::new(Expression::num(n))))
+}
+
+fn errors(files: &[&str]) -> Vec<MglError> {
+  evaluate_constants(&file_tree(files)).unwrap_err()
+}
+
+#[test]
+fn test_constants_evaluation() {
+  let values = constants(&["
+    const TILE  = 16
+    const WIDTH = TILE * 20 + 4 / 2
+    const HALF  = TILE mod 5 - TILE div 5
+    const FLAGS = 1 << 4 | 3
+    const BELOW = -TILE
+    const RATIO = 3 / 2
+    const NAME  = \"mgl\" + \"_\" + \"game\"
+    const BIG   = WIDTH > 300 && !(NAME == \"\")
+    const PICK  = BIG ? TILE : 0
+  "]);
+
+  assert_eq!(values[&constant(&["TILE"])],  expr("16"));
+  assert_eq!(values[&constant(&["WIDTH"])], expr("322"));
+  assert_eq!(values[&constant(&["HALF"])],  negative("-2"));
+  assert_eq!(values[&constant(&["FLAGS"])], expr("19"));
+  assert_eq!(values[&constant(&["BELOW"])], negative("-16"));
+  assert_eq!(values[&constant(&["RATIO"])], expr("1.5"));
+  assert_eq!(values[&constant(&["NAME"])],  expr("\"mgl_game\""));
+  assert_eq!(values[&constant(&["BIG"])],   expr("true"));
+  assert_eq!(values[&constant(&["PICK"])],  expr("16"));
+}
+
+#[test]
+fn test_constants_scopes() {
+  let values = constants(&[
+    "
+    module world
+    const TILE = 16
+    object enemy {
+      const SPEED = TILE / 4
+    }
+    ",
+    "
+    const TILE = 8
+    const DOUBLE = const::world::enemy::SPEED * TILE
+    ",
+  ]);
+
+  assert_eq!(values[&constant(&["world", "enemy", "SPEED"])], expr("4"));
+  assert_eq!(values[&constant(&["DOUBLE"])], expr("32"));
+
+  let module = vec![String::from("world"), String::from("enemy")];
+  let find   = |name, module: &Module| find_constant(&Expression::name(name), module, &values);
+
+  assert_eq!(find("SPEED", &module),        Some(constant(&["world", "enemy", "SPEED"])));
+  assert_eq!(find("TILE",  &module),        Some(constant(&["world", "TILE"])));
+  assert_eq!(find("TILE",  &Module::new()), Some(constant(&["TILE"])));
+  assert_eq!(find("SPEED", &Module::new()), None);
+}
+
+#[test]
+fn test_constants_errors() {
+  let kinds = |files: &[&str]| {
+    errors(files).into_iter().map(|e| match e {
+      MglError::Constant { kind, expression } => (kind, expression.content),
+      _ => unreachable!()
+    }).collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    kinds(&["const A = B + 1\nconst B = C\nconst C = A"]),
+    vec![(ConstantErrorKind::Cycle(["A", "B", "C", "A"].iter().map(|s| s.to_string()).collect()), box Expression::name("A"))]
+  );
+
+  assert_eq!(
+    kinds(&["const A = random(3)\nconst B = A + 1\nconst C = x"]),
+    vec![
+      (ConstantErrorKind::NotConstant, box expr("random(3)").content()),
+      (ConstantErrorKind::NotConstant, box Expression::name("x")),
+    ]
+  );
+
+  assert_eq!(
+    kinds(&["const A = \"a\" - 1\nconst B = 1 / (2 - 2)"]),
+    vec![
+      (ConstantErrorKind::WrongTypes, box expr("\"a\" - 1").content()),
+      (ConstantErrorKind::DivisionByZero, box expr("1 / (2 - 2)").content()),
+    ]
+  );
+}
+
+#[test]
+fn test_constants_enums() {
+  let values = constants(&[
+    "
+    module game
+    enum State { Idle, Walk, Jump = 10, Fall }
+    const AIR = State::Fall - State::Jump
+    ",
+    "
+    import const::game::State
+    enum Team { Red = game::State::Walk + 1, Blue }
+    const PLAYER = Team::Blue * State::Jump
+    ",
+  ]);
+
+  assert_eq!(values[&constant(&["game", "State", "Idle"])], expr("0"));
+  assert_eq!(values[&constant(&["game", "State", "Walk"])], expr("1"));
+  assert_eq!(values[&constant(&["game", "State", "Jump"])], expr("10"));
+  assert_eq!(values[&constant(&["game", "State", "Fall"])], expr("11"));
+  assert_eq!(values[&constant(&["game", "AIR"])],           expr("1"));
+  assert_eq!(values[&constant(&["Team", "Red"])],           expr("2"));
+  assert_eq!(values[&constant(&["PLAYER"])],                expr("30"));
+}
+
+#[test]
+fn test_constants_enum_errors() {
+  let kinds = |code: &str| {
+    errors(&[code]).into_iter().map(|e| match e {
+      MglError::Constant { kind, .. } => kind,
+      _ => unreachable!()
+    }).collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    kinds("enum E { A, B, A }\nconst X = E::C\nfunction f() {\nreturn E::A + E::D\n}"),
+    vec![
+      ConstantErrorKind::DuplicateVariant(String::from("A")),
+      ConstantErrorKind::UnknownVariant(String::from("E"), String::from("D")),
+      ConstantErrorKind::UnknownVariant(String::from("E"), String::from("C")),
+    ]
+  );
+
+  assert_eq!(
+    kinds("enum E { A = 0.5, B = \"b\" }"),
+    vec![ConstantErrorKind::NotInteger, ConstantErrorKind::NotInteger]
+  );
+}
+
+const RESULT_INLINED: &str = r#"
+var __mgl_ternary0;
+if a {
+    __mgl_ternary0 = 4;
+} else {
+    __mgl_ternary0 = "enemy";
+}
+x = (__mgl_ternary0) * 16;
+self.SPEED = 4;
+state = 1;
+return (-1);
+"#;
+
+#[test]
+fn test_constants_inlined_in_scripts() {
+  let files = file_tree(&["
+    const TILE = 16
+    const DOWN = -1
+    enum State { Idle, Walk }
+    object enemy {
+      const SPEED = TILE / 4
+      const NAME  = \"enemy\"
+
+      function step() {
+        x = (a ? SPEED : NAME) * const::TILE
+        self.SPEED = SPEED
+        state = State::Walk
+        return DOWN
+      }
+    }
+  "]);
+
+  let mut project = Project::from_ast_file_tree(files.clone(), Module::new()).unwrap();
+  project.attach_constants(&evaluate_constants(&files).unwrap());
+
+  let step = match &project.scripts[0] {
+    Item::File(_, script) => script.clone(),
+    _ => unreachable!()
+  };
+  assert_eq!(build_script(step).unwrap().trim(), RESULT_INLINED.trim());
+}
+
+const RESULT_LOCALS: &str = r#"
+var TILE = argument0;
+TILE = 3;
+for (var TILE = 0; TILE < 3; TILE += 1) {
+    show(TILE);
+}
+SIZE += 1;
+SIZE[0] = 2;
+show(2);
+"#;
+
+#[test]
+fn test_constants_keep_local_names() {
+  let files = file_tree(&["
+    const TILE = 16
+    const SIZE = 2
+
+    function f(TILE) {
+      TILE = 3
+      for TILE in 0..3 {
+        show(TILE)
+      }
+      SIZE += 1
+      SIZE[0] = 2
+      show(SIZE)
+    }
+  "]);
+
+  let mut project = Project::from_ast_file_tree(files.clone(), Module::new()).unwrap();
+  project.attach_constants(&evaluate_constants(&files).unwrap());
+
+  let f = match &project.scripts[0] {
+    Item::File(_, script) => script.clone(),
+    _ => unreachable!()
+  };
+  assert_eq!(build_script(f).unwrap().trim(), RESULT_LOCALS.trim());
+}
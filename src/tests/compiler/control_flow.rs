@@ -0,0 +1,92 @@
+use crate::tests::utility::*;
+use crate::error::*;
+use crate::compiler::control_flow::*;
+
+fn check(code: &str) -> TopResult<()> {
+  check_control_flow(&file_tree(&[code]))
+}
+
+#[test]
+fn test_control_flow_inside_loops() {
+  assert_eq!(check(r#"
+    function f() {
+      while true {
+        if a {
+          break
+        }
+        continue
+      }
+      for i in 0..10 {
+        repeat 3 {
+          break
+        }
+      }
+      with all {
+        continue
+      }
+      do {
+        break
+      } until a
+      while true {
+        switch a {
+          case 1 {
+            continue
+          }
+          default {
+            repeat 3 {
+              break
+            }
+          }
+        }
+      }
+      exit
+    }
+  "#), Ok(()));
+}
+
+#[test]
+fn test_control_flow_outside_loops() {
+  let errors = check(r#"
+    object o {
+      function step() {
+        if a {
+          break
+        }
+        switch a {
+          default {
+            continue
+          }
+        }
+      }
+    }
+  "#).unwrap_err();
+
+  assert_eq!(errors, vec![
+    MglError::OutsideLoop { statement: statement("break") },
+    MglError::OutsideLoop { statement: statement("continue") },
+  ]);
+
+  match &errors[0] {
+    MglError::OutsideLoop { statement } => assert_eq!((statement.line_start, statement.column_start), (4, 11)),
+    _ => unreachable!()
+  }
+}
+
+#[test]
+fn test_control_flow_break_in_switch() {
+  let errors = check(r#"
+    function f() {
+      while true {
+        switch a {
+          case 1 {
+            if b {
+              break
+            }
+          }
+        }
+      }
+    }
+  "#).unwrap_err();
+
+  assert_eq!(errors, vec![MglError::BreakInSwitch { statement: statement("break") }]);
+}
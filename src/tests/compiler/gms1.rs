@@ -0,0 +1,83 @@
+use std::env::temp_dir;
+use std::fs::{read, read_to_string, remove_dir_all};
+use std::path::{Path, PathBuf};
+use crate::tests::utility::*;
+use crate::compiler::export::*;
+use crate::compiler::gms1::*;
+use crate::compiler::resource_tree::*;
+use crate::utility::files::valid_paths;
+
+fn files_in(root: &Path, directory: &Path) -> Vec<PathBuf> {
+  let mut files = Vec::new();
+
+  for path in valid_paths(directory.read_dir().unwrap()) {
+    if path.is_dir() {
+      files.append(&mut files_in(root, &path));
+    } else {
+      files.push(path.strip_prefix(root).unwrap().to_path_buf());
+    }
+  }
+  files.sort();
+  files
+}
+
+pub fn assert_same_tree(result: &Path, expected: &Path) {
+  let result_files   = files_in(result, result);
+  let expected_files = files_in(expected, expected);
+  assert_eq!(result_files, expected_files);
+
+  // images and sounds are compared byte for byte, everything else as text to show the difference
+  for file in expected_files {
+    let result_file   = read(result.join(&file)).unwrap();
+    let expected_file = read(expected.join(&file)).unwrap();
+
+    match (String::from_utf8(result_file.clone()), String::from_utf8(expected_file.clone())) {
+      (Ok(result_text), Ok(expected_text)) => {
+        assert_eq!(result_text, expected_text, "{:?} differs from the expected output", file)
+      }
+      _ => assert!(result_file == expected_file, "{:?} differs from the expected output", file),
+    }
+  }
+}
+
+#[test]
+fn test_compiler_gms1_hello_project() {
+  let source  = Path::new("examples/hello_project");
+  let output  = temp_dir().join("mgl_test_gms1");
+  let project = read_resource_tree(Some(source.join("Project.mgl.toml"))).unwrap();
+  let _ = remove_dir_all(&output);
+
+  let context   = ExportContext::new("hello_project", source, &output);
+  let directory = export_gms1(&project, &context).unwrap();
+
+  assert_same_tree(&directory, &source.join("expected/gms1/hello_project.gmx"));
+}
+
+#[test]
+fn test_compiler_gms1_asset_names() {
+  assert_eq!(asset_name(&rn!(script::f)),        "f");
+  assert_eq!(asset_name(&rn!(script::o::m)),     "o__m");
+  assert_eq!(asset_name(&rn!(object::o)),        "object__o");
+  assert_eq!(asset_name_in("sprite", &rn!(s)),   "sprite__s");
+  assert_eq!(asset_name_in("sprite", &rn!(sprite::s)), "sprite__s");
+}
+
+#[test]
+fn test_compiler_gms1_room_creation_code() {
+  let project = project_of("
+    function init() {}
+    room r {
+      creation_code: init
+      views[1].angle: 90
+    }
+  ");
+  let output = temp_dir().join("mgl_test_gms1_room_creation_code");
+  let _ = remove_dir_all(&output);
+
+  let context   = ExportContext::new("room_creation_code", Path::new("."), &output);
+  let directory = export_gms1(&project, &context).unwrap();
+  let room      = read_to_string(directory.join("rooms/room__r.room.gmx")).unwrap();
+
+  assert!(room.contains("<code>init();\nview_angle[1] = 90;\n</code>"));
+  let _ = remove_dir_all(&output);
+}
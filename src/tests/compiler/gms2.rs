@@ -0,0 +1,109 @@
+use std::env::temp_dir;
+use std::fs::{read_to_string, remove_dir_all};
+use std::path::Path;
+use crate::tests::utility::*;
+use crate::tests::compiler::gms1::assert_same_tree;
+use crate::compiler::export::*;
+use crate::compiler::gms2::*;
+use crate::compiler::guid::*;
+use crate::compiler::resource_tree::*;
+use crate::error::*;
+
+#[test]
+fn test_compiler_gms2_hello_project() {
+  let source  = Path::new("examples/hello_project");
+  let output  = temp_dir().join("mgl_test_gms2");
+  let project = read_resource_tree(Some(source.join("Project.mgl.toml"))).unwrap();
+  let _ = remove_dir_all(&output);
+
+  let context   = ExportContext::new("hello_project", source, &output);
+  let directory = export_gms2(&project, &context).unwrap();
+
+  assert_same_tree(&directory, &source.join("expected/gms2/hello_project"));
+}
+
+#[test]
+fn test_compiler_gms2_guids() {
+  let guid = resource_guid(&rn!(object::o));
+
+  assert_eq!(guid, resource_guid(&rn!(object::o)));
+  assert_ne!(guid, resource_guid(&rn!(sprite::o)));
+  assert_eq!(guid.to_string().len(), 36);
+  assert_eq!(&guid.to_string()[14..15], "5");
+  assert_eq!(Guid::nil().to_string(), "00000000-0000-0000-0000-000000000000");
+}
+
+#[test]
+fn test_compiler_gms2_target() {
+  assert_eq!("gms1".parse::<Target>(), Ok(Target::Gms1));
+  assert_eq!("gms2".parse::<Target>(), Ok(Target::Gms2));
+  assert_eq!("gms3".parse::<Target>(), MglError::unknown_option("gms3", TARGET_NAMES));
+}
+
+#[test]
+fn test_compiler_gms2_texture_groups() {
+  let project = project_of("
+    font f { texture_group: 1\n }
+    sprite s { texture.texture_group: 2\n }
+  ");
+  let output = temp_dir().join("mgl_test_gms2_texture_groups");
+  let _ = remove_dir_all(&output);
+
+  // like sprites, fonts of any group go in the default one
+  let context   = ExportContext::new("texture_groups", Path::new("."), &output);
+  let directory = export_gms2(&project, &context).unwrap();
+  let font      = read_to_string(directory.join("fonts/font__f/font__f.yy")).unwrap();
+  let sprite    = read_to_string(directory.join("sprites/sprite__s/sprite__s.yy")).unwrap();
+
+  let group = "\"textureGroupId\": \"1225f6b0-ac20-43bd-a82e-be73fa0b6f4f\"";
+  assert!(font.contains(group));
+  assert!(sprite.contains(group));
+  let _ = remove_dir_all(&output);
+}
+
+#[test]
+fn test_compiler_gms2_tile_layers() {
+  let project = project_of("
+    background ground { use_as_tileset: true\n }
+    room r {
+      tiles[0].background: ground
+      tiles[0].depth: 15000
+      tiles[1].background: ground
+      tiles[1].depth: 1000000
+      tiles[2].background: ground
+      tiles[2].depth: -20000
+    }
+  ");
+  let output = temp_dir().join("mgl_test_gms2_tile_layers");
+  let _ = remove_dir_all(&output);
+
+  let context   = ExportContext::new("tile_layers", Path::new("."), &output);
+  let directory = export_gms2(&project, &context).unwrap();
+  let room      = read_to_string(directory.join("rooms/room__r/room__r.yy")).unwrap();
+
+  // layers can't be deeper than 15000, so the tiles deeper than that share its layer
+  assert_eq!(room.matches("\"name\": \"Tiles_").count(), 2);
+  assert_eq!(room.matches("\"name\": \"Tiles_15000\"").count(), 1);
+  assert_eq!(room.matches("\"name\": \"Tiles_-15000\"").count(), 1);
+  let _ = remove_dir_all(&output);
+}
+
+#[test]
+fn test_compiler_gms2_room_creation_code() {
+  let project = project_of("
+    function init() {}
+    room r {
+      creation_code: init
+      views[1].angle: 90
+    }
+  ");
+  let output = temp_dir().join("mgl_test_gms2_room_creation_code");
+  let _ = remove_dir_all(&output);
+
+  let context   = ExportContext::new("room_creation_code", Path::new("."), &output);
+  let directory = export_gms2(&project, &context).unwrap();
+  let code      = read_to_string(directory.join("rooms/room__r/RoomCreationCode.gml")).unwrap();
+
+  assert_eq!(code, "init();\ncamera_set_view_angle(view_camera[1], 90);\n");
+  let _ = remove_dir_all(&output);
+}
@@ -0,0 +1,136 @@
+use crate::tests::utility::*;
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::file_reader::*;
+use crate::compiler::imports::*;
+use crate::resources::project::*;
+
+fn resolved_file(files: &[&str], index: usize) -> Top {
+  match resolve_imports(file_tree(files)).unwrap().children().remove(index) {
+    AstFileTree::Leaf(_, top) => top,
+    _ => unreachable!()
+  }
+}
+
+#[test]
+fn test_imports_file_module() {
+  assert_eq!(file_module(&parse_unwrap("object a {}")), Module::new());
+  assert_eq!(file_module(&parse_unwrap("module a::b\nobject a {}")), vec!["a", "b"]);
+}
+
+#[test]
+fn test_imports_aliases() {
+  let player = "
+    module player
+    sprite idle {}
+    sound jump {}
+  ";
+
+  let game = "
+    module game
+    import sprite::player::idle
+    import sound::player as sounds
+    object hero {
+      sprite: idle
+
+      function jump() {
+        play(sounds::jump)
+        self.idle = idle
+      }
+    }
+  ";
+
+  let top = resolved_file(&[player, game], 1);
+  let hero = match &top.declarations[3] {
+    Declaration::Resource(resource) => resource.clone(),
+    _ => unreachable!()
+  };
+
+  assert_eq!(hero.key_value_pairs[0].value, expr("sprite::player::idle"));
+  assert_eq!(
+    hero.methods[0].body,
+    statement("{\nplay(sound::player::jump)\nself.idle = sprite::player::idle\n}")
+  );
+}
+
+#[test]
+fn test_imports_local_names() {
+  let player = "
+    module player
+    sprite idle {}
+    sprite run {}
+    sprite walk {}
+  ";
+
+  let game = "
+    import sprite::player::idle
+    import sprite::player::run
+    import sprite::player::walk
+    function animate(idle) {
+      var run = idle
+      for walk in 0..3 {
+        show(idle, run, walk)
+      }
+    }
+    function reset() {
+      show(idle, run, walk)
+    }
+  ";
+
+  let top = resolved_file(&[player, game], 1);
+  let body = |index: usize| match &top.declarations[index] {
+    Declaration::Function(function) => function.body.clone(),
+    _ => unreachable!()
+  };
+
+  assert_eq!(
+    body(3),
+    statement("{\nvar run = idle\nfor walk in 0..3 {\nshow(idle, run, walk)\n}\n}")
+  );
+  assert_eq!(
+    body(4),
+    statement("{\nshow(sprite::player::idle, sprite::player::run, sprite::player::walk)\n}")
+  );
+}
+
+#[test]
+fn test_imports_instances() {
+  let files = [
+    "module things\nobject ball {}",
+    "import object::things::ball\ninstance b of ball {}",
+  ];
+
+  match &resolved_file(&files, 1).declarations[1] {
+    Declaration::Instance(instance) => assert_eq!(instance.object.content_clone(), rn!(object::things::ball)),
+    _ => unreachable!()
+  }
+}
+
+#[test]
+fn test_imports_missing() {
+  let errors = resolve_imports(file_tree(&["import sprite::nowhere::idle"])).unwrap_err();
+
+  assert_eq!(errors, vec![MglError::MissingImport { import: expr("sprite::nowhere::idle") }]);
+}
+
+#[test]
+fn test_imports_cyclic() {
+  let files = [
+    "module a\nimport object::b::y\nobject x {}",
+    "module b\nimport object::c::z\nobject y {}",
+    "module c\nimport object::a::x\nobject z {}",
+  ];
+  let errors = resolve_imports(file_tree(&files)).unwrap_err();
+
+  let module = |name: &str| vec![String::from(name)];
+  assert_eq!(
+    errors,
+    vec![MglError::CyclicImport {
+      import: expr("object::a::x"),
+      cycle: vec![module("a"), module("b"), module("c"), module("a")],
+    }]
+  );
+
+  // importing from your own module is fine
+  assert!(resolve_imports(file_tree(&["module a\nimport object::a::x\nobject x {}"])).is_ok());
+}
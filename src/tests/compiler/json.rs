@@ -0,0 +1,14 @@
+use crate::compiler::json::*;
+
+#[test]
+fn test_compiler_json() {
+  let json = json_object! {
+    "a" => 1,
+    "b" => "x\"y",
+    "c" => vec![Json::Bool(true), Json::Null],
+    "d" => Json::Array(vec![]),
+  };
+
+  assert_eq!(json.compact(), r#"{"a":1,"b":"x\"y","c":[true,null],"d":[]}"#);
+  assert_eq!(json.pretty(), "{\n    \"a\": 1,\n    \"b\": \"x\\\"y\",\n    \"c\": [\n        true,\n        null\n    ],\n    \"d\": []\n}");
+}
@@ -1,3 +1,8 @@
 mod file_reader;
+mod imports;
 mod script;
-
+mod json;
+mod gms1;
+mod gms2;
+mod control_flow;
+mod constants;
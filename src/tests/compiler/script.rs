@@ -1,9 +1,11 @@
 use crate::tests::utility::*;
+use crate::ast::*;
 use crate::compiler::script::*;
 use crate::resources::script::*;
+use crate::error::MglError;
 
 fn script(source: &str) -> String {
-  build_script(Script::global(function(source)))
+  build_script(Script::global(function(source))).unwrap()
 }
 
 fn func(body: &str) -> String {
@@ -56,6 +58,15 @@ fn test_compiler_script_assign() {
   assert_script_eq(&func("a = b"), "a = b;");
 }
 
+#[test]
+fn test_compiler_script_compound_assign() {
+  assert_script_eq(&func("hp -= dmg"), "hp -= dmg;");
+  assert_script_eq(&func("flags |= MASK"), "flags |= MASK;");
+  assert_script_eq(&func("i++"), "i++;");
+  assert_script_eq(&func("--i"), "--i;");
+  assert_script_eq(&func("x = a mod b div c"), "x = a % b div c;");
+}
+
 #[test]
 fn test_compiler_script_var() {
   assert_script_eq(&func("var a"),        "var a;");
@@ -107,13 +118,287 @@ fn test_compiler_script_while() {
 }
 
 const RESULT_FOR: &str = r#"
-for (var i = 0; i != 10; i += 1) {
+for (var i = 0; i < 10; i += 1) {
+    print(i);
+}
+"#;
+
+const RESULT_FOR_NEGATIVE: &str = r#"
+for (var i = 10; i > 0; i += -2) {
+    print(i);
+}
+"#;
+
+const RESULT_FOR_STEP: &str = r#"
+var __mgl_step0 = s;
+for (var i = 0; (__mgl_step0 > 0 && i < n) || (__mgl_step0 < 0 && i > n); i += __mgl_step0) {
     print(i);
 }
 "#;
 
+const RESULT_FOR_ARRAY: &str = r#"
+var __mgl_array0 = enemies;
+for (var __mgl_index0 = 0; __mgl_index0 < array_length_1d(__mgl_array0); __mgl_index0 += 1) {
+    var e = __mgl_array0[__mgl_index0];
+    print(e);
+}
+"#;
+
 #[test]
 fn test_compiler_script_for() {
   assert_script_eq(&func("for i in 0..10 by 1 {print(i)\n}"), RESULT_FOR);
+  assert_script_eq(&func("for i in 0..10 {print(i)\n}"), RESULT_FOR);
+  assert_script_eq(&func("for i in 10..0 by -2 {print(i)\n}"), RESULT_FOR_NEGATIVE);
+  assert_script_eq(&func("for i in 0..n by s {print(i)\n}"), RESULT_FOR_STEP);
+  assert_script_eq(&func("for e in enemies {print(e)\n}"), RESULT_FOR_ARRAY);
+}
+
+
+const RESULT_REPEAT: &str = r#"
+repeat 3 {
+    if done {
+        break;
+    }
+    spawn();
+}
+"#;
+
+const RESULT_DO_UNTIL: &str = r#"
+do {
+    i = i + 1;
+} until i > 10;
+"#;
+
+const RESULT_DO_UNTIL_LOWERED: &str = r#"
+while true {
+    if skip {
+        var __mgl_ternary0;
+        if a {
+            __mgl_ternary0 = i > 10;
+        } else {
+            __mgl_ternary0 = i > 20;
+        }
+        if __mgl_ternary0 {
+            break;
+        }
+        continue;
+    }
+    i = i + 1;
+    var __mgl_ternary1;
+    if a {
+        __mgl_ternary1 = i > 10;
+    } else {
+        __mgl_ternary1 = i > 20;
+    }
+    if __mgl_ternary1 {
+        break;
+    }
+}
+"#;
+
+#[test]
+fn test_compiler_script_loop_control() {
+  assert_script_eq(&func("while true {continue\n}"), "while true {\n    continue;\n}");
+  assert_script_eq(&func("exit"), "exit;");
+  assert_script_eq(&func("repeat 3 {\nif done {break\n}\nspawn()\n}"), RESULT_REPEAT);
+  assert_script_eq(&func("do {i = i + 1\n} until i > 10"), RESULT_DO_UNTIL);
+  assert_script_eq(
+    &func("do {\nif skip {continue\n}\ni = i + 1\n} until a ? i > 10 : i > 20"),
+    RESULT_DO_UNTIL_LOWERED
+  );
+}
+
+
+const RESULT_SWITCH: &str = r#"
+switch state {
+    case 0:
+    case 1:
+        x = 1;
+        break;
+    case 2:
+        break;
+    default:
+        x = 2;
+        break;
+}
+"#;
+
+#[test]
+fn test_compiler_script_switch() {
+  assert_script_eq(
+    &func("switch state {\ncase 0, 1 {x = 1\n}\ncase 2 {}\ndefault {x = 2\n}\n}"),
+    RESULT_SWITCH
+  );
+}
+
+
+const RESULT_TERNARY: &str = r#"
+var __mgl_ternary0;
+if a > b {
+    __mgl_ternary0 = a;
+} else {
+    __mgl_ternary0 = b;
+}
+return (__mgl_ternary0) + 1;
+"#;
+
+const RESULT_TERNARY_NESTED: &str = r#"
+var __mgl_ternary0;
+if a {
+    var __mgl_ternary1;
+    if b {
+        __mgl_ternary1 = 1;
+    } else {
+        __mgl_ternary1 = 2;
+    }
+    __mgl_ternary0 = (__mgl_ternary1);
+} else {
+    __mgl_ternary0 = 3;
+}
+x = __mgl_ternary0;
+"#;
+
+const RESULT_TERNARY_WHILE: &str = r#"
+while true {
+    var __mgl_ternary0;
+    if a {
+        __mgl_ternary0 = i < 10;
+    } else {
+        __mgl_ternary0 = i < 20;
+    }
+    if !(__mgl_ternary0) {
+        break;
+    }
+    i = i + 1;
+}
+"#;
+
+const RESULT_TERNARY_AND: &str = r#"
+var __mgl_and1 = false;
+if x != noone {
+    var __mgl_ternary0;
+    if x.hp > 0 {
+        __mgl_ternary0 = true;
+    } else {
+        __mgl_ternary0 = false;
+    }
+    if (__mgl_ternary0) {
+        __mgl_and1 = true;
+    }
+}
+if __mgl_and1 {
+    kill(x);
+}
+"#;
+
+const RESULT_TERNARY_OR: &str = r#"
+var __mgl_or1 = true;
+if !(done) {
+    var __mgl_ternary0;
+    if a {
+        __mgl_ternary0 = b;
+    } else {
+        __mgl_ternary0 = c;
+    }
+    if !((__mgl_ternary0)) {
+        __mgl_or1 = false;
+    }
+}
+return __mgl_or1;
+"#;
+
+const RESULT_TERNARY_FOR: &str = r#"
+for (var i = 0; true; i += 1) {
+    var __mgl_ternary0;
+    if a {
+        __mgl_ternary0 = n;
+    } else {
+        __mgl_ternary0 = m;
+    }
+    if !(i < (__mgl_ternary0)) {
+        break;
+    }
+    print(i);
+}
+"#;
+
+#[test]
+fn test_compiler_script_ternary() {
+  assert_script_eq(&func("return (a > b ? a : b) + 1"), RESULT_TERNARY);
+  assert_script_eq(&func("x = a ? (b ? 1 : 2) : 3"), RESULT_TERNARY_NESTED);
+  assert_script_eq(&func("while a ? i < 10 : i < 20 {i = i + 1\n}"), RESULT_TERNARY_WHILE);
+  assert_script_eq(&func("if x != noone && (x.hp > 0 ? true : false) {kill(x)\n}"), RESULT_TERNARY_AND);
+  assert_script_eq(&func("return done || (a ? b : c)"), RESULT_TERNARY_OR);
+  assert_script_eq(&func("for i in 0..(a ? n : m) {print(i)\n}"), RESULT_TERNARY_FOR);
+  assert_script_eq(&func("return a && b"), "return a && b;");
+
+  let native = build_script_for(Script::global(function(&func("x = a ? b : c"))), GmlFeatures::GMS2).unwrap();
+  assert_eq!(native.trim(), "x = a ? b : c;");
 }
 
+
+const RESULT_ARRAY: &str = r#"
+var __mgl_array0 = array_create(3);
+__mgl_array0[0] = 1;
+__mgl_array0[1] = 2;
+__mgl_array0[2] = 3;
+xs = __mgl_array0;
+"#;
+
+const RESULT_MAP: &str = r#"
+var __mgl_array0 = array_create(2);
+__mgl_array0[0] = 1;
+__mgl_array0[1] = 2;
+var __mgl_map1 = ds_map_create();
+ds_map_add(__mgl_map1, "hp", 10);
+ds_map_add(__mgl_map1, "drops", __mgl_array0);
+stats = __mgl_map1;
+"#;
+
+const RESULT_MAP_AND: &str = r#"
+var __mgl_and1 = false;
+if ready {
+    var __mgl_map0 = ds_map_create();
+    ds_map_add(__mgl_map0, "hp", 10);
+    if save(__mgl_map0) {
+        __mgl_and1 = true;
+    }
+}
+ok = __mgl_and1;
+"#;
+
+#[test]
+fn test_compiler_script_collection_literals() {
+  assert_script_eq(&func("xs = [1, 2, 3]"), RESULT_ARRAY);
+  assert_script_eq(&func("stats = {\"hp\": 10, \"drops\": [1, 2]}"), RESULT_MAP);
+  assert_script_eq(&func("ok = ready && save({\"hp\": 10})"), RESULT_MAP_AND);
+
+  let features = GmlFeatures { array_literals: true, ..GmlFeatures::GMS2 };
+  let native   = build_script_for(Script::global(function(&func("xs = [1, [2]]"))), features).unwrap();
+  assert_eq!(native.trim(), "xs = [1, [2]];");
+}
+
+#[test]
+fn test_compiler_script_map_not_lowered() {
+  let mut global = Script::global(function(&func("stats = STATS")));
+  global.constants.insert(ResourceName::Name(String::from("STATS")), expr("{\"hp\": 10}"));
+
+  match build_script(global) {
+    Err(MglError::ConvertExpression { value, .. }) => assert_eq!(value, expr("{\"hp\": 10}")),
+    result => panic!("expected a conversion error, got {:?}", result),
+  }
+}
+
+#[test]
+fn test_compiler_script_instance_create_depth() {
+  let gms2 = |body: &str| build_script_for(Script::global(function(&func(body))), GmlFeatures::GMS2).unwrap();
+
+  assert_eq!(gms2("i = instance_create(x, y, enemy)").trim(),
+             "i = instance_create_depth(x, y, object_get_depth(enemy), enemy);");
+  assert_eq!(gms2("i = instance_create(0, 0, kinds[k])").trim(), "
+var __mgl_object0 = kinds[k];
+i = instance_create_depth(0, 0, object_get_depth(__mgl_object0), __mgl_object0);
+  ".trim());
+
+  assert_script_eq(&func("instance_create(x, y, enemy)"), "instance_create(x, y, enemy);");
+}
@@ -1 +1,2 @@
 mod files;
+mod strings;
@@ -0,0 +1,24 @@
+use crate::utility::strings::*;
+
+#[test]
+fn test_utility_strings_edit_distance() {
+  assert_eq!(edit_distance("", ""), 0);
+  assert_eq!(edit_distance("abc", ""), 3);
+  assert_eq!(edit_distance("", "abc"), 3);
+  assert_eq!(edit_distance("player", "player"), 0);
+  assert_eq!(edit_distance("player", "palyer"), 2);
+  assert_eq!(edit_distance("kitten", "sitting"), 3);
+}
+
+#[test]
+fn test_utility_strings_closest() {
+  let names = vec!["player", "enemy", "wall", "ui::score"];
+
+  assert_eq!(closest("playr", names.clone()),     Some("player"));
+  assert_eq!(closest("wal", names.clone()),       Some("wall"));
+  assert_eq!(closest("ememy", names.clone()),     Some("enemy"));
+  assert_eq!(closest("ui::scor", names.clone()),  Some("ui::score"));
+  assert_eq!(closest("hero", names.clone()),      None);
+  assert_eq!(closest("x", names.clone()),         None);
+  assert_eq!(closest("anything", Vec::new()),     None);
+}
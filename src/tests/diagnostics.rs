@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+use crate::error::*;
+use crate::parser::parse_code;
+use crate::parser::context::ParserContext;
+use crate::source_files::SourceFile;
+use crate::ast::Declaration;
+use crate::resources::object::Object;
+use crate::tests::utility::project_of;
+
+fn diagnostic_line(e: MglError) -> String {
+  diagnostic::<DefaultErrorMessages>(e).compact()
+}
+
+#[test]
+fn test_diagnostics_parser_error() {
+  let file  = SourceFile::new(PathBuf::from("/game/src/diagnostics.mgl"));
+  let error = parse_code(ParserContext::new("object {}").with_file(file)).unwrap_err();
+
+  assert_eq!(diagnostic_line(error), concat!(
+    r#"{"severity":"error","code":"E0001","file":"/game/src/diagnostics.mgl","#,
+    r#""line_start":1,"column_start":8,"line_end":1,"column_end":8,"#,
+    r#""message":"Expected a name.","expected":["name"]}"#
+  ));
+}
+
+fn object_error(code: &str) -> MglError {
+  let file = SourceFile::new(PathBuf::from("/game/src/object.mgl"));
+  let top  = parse_code(ParserContext::new(code).with_file(file)).unwrap();
+
+  match top.declarations.into_iter().next() {
+    Some(Declaration::Resource(resource)) => Object::new(resource).unwrap_err(),
+    _ => unreachable!()
+  }
+}
+
+#[test]
+fn test_diagnostics_field_locations() {
+  let span = |e: MglError| e.location().and_then(|location| location.span).unwrap();
+
+  // the misspelled key, rather than its value
+  let unknown_field = span(object_error("object o {\n  spirte: s\n}"));
+  assert_eq!((unknown_field.line_start, unknown_field.column_start, unknown_field.column_end), (2, 3, 9));
+
+  // the index of the event, when that's what is wrong
+  let unknown_alarm = span(object_error("object o {\n  alarm[13]: tick\n}"));
+  assert_eq!((unknown_alarm.line_start, unknown_alarm.column_start, unknown_alarm.column_end), (2, 9, 11));
+
+  let unknown_event = span(object_error("object o {\n  a.b: tick\n}"));
+  assert_eq!((unknown_event.line_start, unknown_event.column_start, unknown_event.column_end), (2, 3, 6));
+}
+
+#[test]
+fn test_diagnostics_unknown_instance() {
+  let mut project = project_of("room r {\n  instances[0]: nope\n}");
+
+  // the key in the room, rather than the name of the instance
+  let errors = project.link_instances().unwrap_err();
+  let span   = errors[0].location().and_then(|location| location.span).unwrap();
+  assert_eq!((span.line_start, span.column_start, span.column_end), (2, 3, 15));
+}
+
+#[test]
+fn test_diagnostics_without_span() {
+  let error = MglError::Io {
+    path: PathBuf::from("/game/src/missing.mgl"),
+    message: String::from("No such file or directory"),
+  };
+
+  assert_eq!(diagnostic_line(error), concat!(
+    r#"{"severity":"error","code":"E0007","file":"/game/src/missing.mgl","#,
+    r#""line_start":null,"column_start":null,"line_end":null,"column_end":null,"#,
+    r#""message":"No such file or directory","expected":[]}"#
+  ));
+
+  assert_eq!(diagnostic_line(MglError::NotResource), concat!(
+    r#"{"severity":"error","code":"E0004","file":null,"#,
+    r#""line_start":null,"column_start":null,"line_end":null,"column_end":null,"#,
+    r#""message":"Expected a single resource declaration","expected":[]}"#
+  ));
+
+  assert_eq!(diagnostic_line(MglError::NoProjectFile), concat!(
+    r#"{"severity":"error","code":"E0016","file":null,"#,
+    r#""line_start":null,"column_start":null,"line_end":null,"column_end":null,"#,
+    r#""message":"No project file was found, nothing to compile!","expected":[]}"#
+  ));
+}
+
+#[test]
+fn test_diagnostics_error_format() {
+  assert_eq!("human".parse::<ErrorFormat>(), Ok(ErrorFormat::Human));
+  assert_eq!("json".parse::<ErrorFormat>(),  Ok(ErrorFormat::Json));
+  assert!("xml".parse::<ErrorFormat>().is_err());
+}
@@ -0,0 +1,93 @@
+use std::path::{Path, PathBuf};
+use crate::ast::*;
+use crate::error::*;
+use crate::compiler::export::Target;
+use crate::manifest::*;
+
+fn manifest_errors(contents: &str) -> Vec<(ManifestErrorKind, String, usize)> {
+  match ProjectManifest::parse(Path::new("/game/Project.mgl.toml"), contents) {
+    Err(errors) => {
+      errors.into_iter().map(|error| match error {
+        MglError::Manifest { kind, key, line, .. } => (kind, key, line),
+        error => panic!("expected a manifest error, got {:?}", error)
+      }).collect()
+    }
+    result => panic!("expected manifest errors, got {:?}", result)
+  }
+}
+
+fn manifest_error(contents: &str) -> (ManifestErrorKind, String, usize) {
+  let mut errors = manifest_errors(contents);
+  assert_eq!(errors.len(), 1);
+  errors.remove(0)
+}
+
+#[test]
+fn test_manifest_defaults() {
+  let manifest = ProjectManifest::parse(Path::new("/game/Project.mgl.toml"), "").unwrap();
+
+  assert_eq!(manifest.name, "game");
+  assert_eq!(manifest.directory, PathBuf::from("/game"));
+  assert_eq!(manifest.sources, vec![PathBuf::from("/game/src")]);
+  assert_eq!(manifest.output, PathBuf::from("/game/build"));
+  assert_eq!(manifest.assets, vec![PathBuf::from("/game")]);
+  assert_eq!(manifest.target, Target::Gms1);
+  assert_eq!(manifest.default_room, None);
+}
+
+#[test]
+fn test_manifest_keys() {
+  let manifest = ProjectManifest::parse(Path::new("/game/Project.mgl.toml"), r#"
+    name = "platformer"
+    sources = ["src", "lib"]
+    output = "out"
+    target = "gms2"
+    default_room = "levels::title"
+    assets = ["assets"]
+  "#).unwrap();
+
+  assert_eq!(manifest.name, "platformer");
+  assert_eq!(manifest.sources, vec![PathBuf::from("/game/src"), PathBuf::from("/game/lib")]);
+  assert_eq!(manifest.output, PathBuf::from("/game/out"));
+  assert_eq!(manifest.assets, vec![PathBuf::from("/game/assets")]);
+  assert_eq!(manifest.target, Target::Gms2);
+  assert_eq!(manifest.default_room, Some(ResourceName::new(&["room", "levels", "title"])));
+}
+
+#[test]
+fn test_manifest_errors() {
+  assert_eq!(
+    manifest_error("name = \"game\"\n\noutptu = \"build\""),
+    (ManifestErrorKind::UnknownKey, String::from("outptu"), 3),
+  );
+
+  assert_eq!(
+    manifest_error("sources = \"src\""),
+    (ManifestErrorKind::WrongType(String::from("array of strings")), String::from("sources"), 1),
+  );
+
+  assert_eq!(
+    manifest_error("name = \"game\"\ntarget = \"gms3\""),
+    (ManifestErrorKind::UnknownValue(String::from("gms3"), vec![String::from("gms1"), String::from("gms2")]),
+     String::from("target"), 2),
+  );
+
+  if let (ManifestErrorKind::Syntax(_), _, line) = manifest_error("name = \"game\"\noutput = ") {
+    assert_eq!(line, 2);
+  } else {
+    panic!("expected a syntax error");
+  }
+}
+
+#[test]
+fn test_manifest_every_error() {
+  assert_eq!(
+    manifest_errors("target = \"gms3\"\nname = \"game\"\nsources = \"src\"\noutptu = \"build\""),
+    vec![
+      (ManifestErrorKind::UnknownValue(String::from("gms3"), vec![String::from("gms1"), String::from("gms2")]),
+       String::from("target"), 1),
+      (ManifestErrorKind::WrongType(String::from("array of strings")), String::from("sources"), 3),
+      (ManifestErrorKind::UnknownKey, String::from("outptu"), 4),
+    ],
+  );
+}
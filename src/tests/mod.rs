@@ -6,3 +6,5 @@ mod compiler;
 mod resources;
 mod source_files;
 
+mod manifest;
+mod diagnostics;
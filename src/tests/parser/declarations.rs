@@ -1,6 +1,7 @@
 use crate::ast::*;
 use crate::error::*;
 use crate::tests::utility::*;
+use crate::parser::parse_code;
 use std::str::FromStr;
 use std::fmt::Debug;
 
@@ -77,6 +78,8 @@ fn test_declaration_key_value() {
   assert_parse_declaration("x:   1",      KeyValue::new(key("x"),    expr("1")));
   assert_parse_declaration("x.y: true",   KeyValue::new(key("x.y"),  expr("true")));
   assert_parse_declaration("x[0]: \"k\"", KeyValue::new(key("x[0]"), expr("\"k\"")));
+  assert_parse_declaration("x:   [1, 2]", KeyValue::new(key("x"),    expr("[1, 2]")));
+  assert_parse_declaration("x:   {a: 1}", KeyValue::new(key("x"),    expr("{a: 1}")));
 }
 
 
@@ -163,10 +166,12 @@ fn test_declaration_resource_misc_1() {
   let ball_sprite = resource!(Sprite, "ball",   &keys![radius: 21, is_bouncy: true], &[], &[]);
   let ball_sound  = resource!(Sound, "boing",   &keys![loops: false, length: 12.5],  &[], &[]);
   let ball_room   = resource!(Room, "ballroom", &keys![has: ball, many: true], &[], &[]);
+  let ball_font   = resource!(Font, "score",    &keys![size: 12],                 &[], &[]);
 
   assert_parse_declaration("sprite ball { radius: 21\n is_bouncy: true\n }", ball_sprite);
   assert_parse_declaration("sound  boing { loops: false\n length: 12.5\n }", ball_sound);
   assert_parse_declaration("room   ballroom { has: ball\n many: true\n }",   ball_room);
+  assert_parse_declaration("font   score { size: 12\n }",                     ball_font);
 }
 
 #[test]
@@ -177,9 +182,73 @@ fn test_declaration_resource_module() {
   };
 
   assert_eq!(resource_module("object  a {}"), "object");
-  assert_eq!(resource_module("wrapper a {}"), "wrapper");
+  assert_eq!(resource_module("wrapper a {}"), "object");
   assert_eq!(resource_module("sprite  a {}"), "sprite");
   assert_eq!(resource_module("sound   a {}"), "sound");
+  assert_eq!(resource_module("font    a {}"), "font");
+  assert_eq!(resource_module("path    a {}"), "path");
+  assert_eq!(resource_module("background a {}"), "background");
   assert_eq!(resource_module("room    a {}"), "room");
 }
 
+
+#[test]
+fn test_declaration_module() {
+  assert_parse_declaration("module player", ModuleDeclaration::new(&["player"]));
+  assert_parse_declaration("module game::player", ModuleDeclaration::new(&["game", "player"]));
+}
+
+#[test]
+fn test_declaration_import() {
+  let idle = || expr("sprite::player::idle");
+
+  assert_parse_declaration("import sprite::player::idle", ImportDeclaration::new(idle(), None));
+  assert_parse_declaration("use sprite::player::idle", ImportDeclaration::new(idle(), None));
+  assert_parse_declaration(
+    "import sprite::player::idle as standing",
+    ImportDeclaration::new(idle(), Some("standing"))
+  );
+
+  assert_eq!(parse_unwrap::<ImportDeclaration>("import sprite::player::idle").alias, "idle");
+  assert_eq!(parse_unwrap::<ImportDeclaration>("import sprite::player as p").alias, "p");
+  assert_eq!(
+    parse_unwrap::<ImportDeclaration>("import sprite::player::idle").resource_name(),
+    rn!(sprite::player::idle)
+  );
+}
+
+#[test]
+fn test_declaration_constant() {
+  assert_parse_declaration("const SPEED = 4", ConstantDeclaration::new("SPEED", expr("4")));
+  assert_parse_declaration("const A = B * (2 + C)", ConstantDeclaration::new("A", expr("B * (2 + C)")));
+
+  let enemy = resource("object enemy {\n  const SPEED = 2\n  sprite: s\n}");
+  assert_eq!(enemy.constants, vec![ConstantDeclaration::new("SPEED", expr("2"))]);
+  assert_eq!(enemy.key_value_pairs.len(), 1);
+}
+
+#[test]
+fn test_declaration_enum() {
+  let variant = |name: &str, value: Option<&str>| {
+    EnumVariant::new(AstDebugInfo::new(String::from(name)), value.map(expr))
+  };
+
+  assert_parse_declaration("enum E {}", EnumDeclaration::new("E", &[]));
+  assert_parse_declaration(
+    "enum State { Idle, Walk, Jump = 10 }",
+    EnumDeclaration::new("State", &[variant("Idle", None), variant("Walk", None), variant("Jump", Some("10"))])
+  );
+  assert_parse_declaration(
+    "enum Team {\n  Red = 1 << 2,\n  Blue,\n}",
+    EnumDeclaration::new("Team", &[variant("Red", Some("1 << 2")), variant("Blue", None)])
+  );
+}
+
+#[test]
+fn test_declaration_imports_before_resources() {
+  let Top { declarations } = parse_unwrap("module a\nimport b::c\nobject d {}");
+  assert_eq!(declarations.len(), 3);
+
+  assert!(parse_code("object d {}\nimport b::c").is_err());
+  assert!(parse_code("import b::c\nmodule a").is_err());
+}
@@ -80,7 +80,10 @@ fn test_expression_binary() {
     [("a.b"   , Dot), ("a .  b", Dot),
      ("a +  b", Add), ("a -  b", Sub), ("a *  b", Mul), ("a /  b", Div ),
      ("a || b", Or ), ("a && b", And), ("a <  b", Lt ), ("a >  b", Gt  ),
-     ("a >= b", Geq), ("a <= b", Leq), ("a == b", Eq ), ("a != b", Diff)].iter() {
+     ("a >= b", Geq), ("a <= b", Leq), ("a == b", Eq ), ("a != b", Diff),
+     ("a %  b", Mod), ("a mod b", Mod), ("a div b", IntDiv), ("a ^^ b", Xor),
+     ("a &  b", BitAnd), ("a |  b", BitOr), ("a ^  b", BitXor),
+     ("a << b", Shl), ("a >> b", Shr)].iter() {
 
     assert_parse_expr(code, Expression::binary_op(*op, a.clone(), b.clone()))
   }
@@ -137,6 +140,29 @@ fn test_expression_indexing() {
   assert_parse_expr("a[@i,j]", indexing(Array, vec![&i, &j]));
 }
 
+#[test]
+fn test_expression_array() {
+  let a = name("a");
+  let b = name("b");
+
+  assert_parse_expr("[]",          Expression::array(&[]));
+  assert_parse_expr("[a]",         Expression::array(&[name("a")]));
+  assert_parse_expr("[a, b]",      Expression::array(&[a.clone(), b.clone()]));
+  assert_parse_expr("[\n  a,\n  b\n]", Expression::array(&[a.clone(), b.clone()]));
+  assert_parse_expr("[a][0]",      Expression::indexing(expr("[a]"), Accessor::None, &[expr("0")]));
+}
+
+#[test]
+fn test_expression_map() {
+  let a = name("a");
+  let k = expr("\"k\"");
+
+  assert_parse_expr("{}",              Expression::map(&[]));
+  assert_parse_expr("{\"k\": a}",       Expression::map(&[(k.clone(), a.clone())]));
+  assert_parse_expr("{\"k\": a, a: [a]}", Expression::map(&[(k.clone(), a.clone()), (a.clone(), expr("[a]"))]));
+  assert_parse_expr("{\n  \"k\": a\n}",   Expression::map(&[(k.clone(), a.clone())]));
+}
+
 #[test]
 fn test_expression_multiple_ops() {
   let a = expr("a");
@@ -171,6 +197,13 @@ fn test_expression_multiple_ops() {
   assert_parse_expr("a != b && c == d", bin("&&", &bin("!=", &a, &b), &bin("==", &c, &d)));
   assert_parse_expr("a >= b && c <= d", bin("&&", &bin(">=", &a, &b), &bin("<=", &c, &d)));
 
+  assert_parse_expr("a & b == c",    bin("==", &bin("&", &a, &b), &c));
+  assert_parse_expr("a | b & c",     bin("|", &a, &bin("&", &b, &c)));
+  assert_parse_expr("a << b + c",    bin("<<", &a, &bin("+", &b, &c)));
+  assert_parse_expr("a mod b + c",   bin("+", &bin("mod", &a, &b), &c));
+  assert_parse_expr("a && b ^^ c",   bin("^^", &bin("&&", &a, &b), &c));
+  assert_parse_expr("a ^^ b || c",   bin("||", &bin("^^", &a, &b), &c));
+
   assert_parse_expr("a + f(x)", bin("+", &a, &fx));
   assert_parse_expr("f(x) + a", bin("+", &fx, &a));
 
@@ -2,3 +2,4 @@ mod expressions;
 mod statements;
 mod declarations;
 mod error;
+mod recovery;
@@ -0,0 +1,95 @@
+use crate::ast::*;
+use crate::error::*;
+use crate::parser::parse_code;
+use crate::parser::recovery::parse_code_recovering;
+
+use ParserErrorKind::*;
+
+fn recover(code: &str) -> (Vec<Declaration>, Vec<(ParserErrorKind, usize)>) {
+  let (top, errors) = parse_code_recovering(code);
+  let errors = errors.into_iter().map(|error| {
+    match error {
+      MglError::Parser { error_kind, line, .. } => (error_kind, line),
+      _ => panic!("Not a parser error")
+    }
+  });
+  (top.declarations, errors.collect())
+}
+
+fn declarations(code: &str) -> Vec<Declaration> {
+  parse_code(code).unwrap().declarations
+}
+
+#[test]
+fn test_recovery_valid_code() {
+  let code = "object a {\n  x: 1\n}\nfunction f() {\n  return 1\n}\n";
+  assert_eq!(recover(code), (declarations(code), vec![]));
+}
+
+#[test]
+fn test_recovery_declarations() {
+  let (top, errors) = recover(
+    "object {}\nobject a {\n}\nfunction () {\n}\nfunction f() {\n}\n"
+  );
+
+  assert_eq!(top, declarations("object a {\n}\nfunction f() {\n}\n"));
+  assert_eq!(errors, vec![(MissingName, 1), (MissingName, 4)]);
+}
+
+#[test]
+fn test_recovery_statements() {
+  let (top, errors) = recover(r#"
+function f() {
+  var a = 1
+  a = = 2
+  if a {
+    b = )
+  }
+  return a
+}
+"#);
+
+  let expected = declarations(r#"
+function f() {
+  var a = 1
+  if a {
+  }
+  return a
+}
+"#);
+
+  assert_eq!(top, expected);
+  assert_eq!(errors.iter().map(|e| e.1).collect::<Vec<_>>(), vec![4, 6]);
+}
+
+#[test]
+fn test_recovery_resource_items() {
+  let (top, errors) = recover(r#"
+object a {
+  x: 1
+  y:
+  function f() {
+    return (1
+  }
+}
+"#);
+
+  let expected = declarations(r#"
+object a {
+  x: 1
+  function f() {
+  }
+}
+"#);
+
+  assert_eq!(top, expected);
+  assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_recovery_unclosed_declaration() {
+  let (top, errors) = recover("object a {\n  x: 1\n\nobject b {\n  y: 2\n}\n");
+
+  assert_eq!(top, declarations("object b {\n  y: 2\n}\n"));
+  assert_eq!(errors.len(), 1);
+}
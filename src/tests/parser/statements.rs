@@ -17,6 +17,8 @@ fn test_statement_call() {
   assert_parse_statement!("f()",    Statement::call(expr("f()")));
   assert_parse_statement!("f(a)",   Statement::call(expr("f(a)")));
   assert_parse_statement!("g(x,y)", Statement::call(expr("g(x,y)")));
+  assert_parse_statement!("self.f(a)", Statement::call(expr("self.f(a)")));
+  assert_parse_statement!("c ? f : g(a)", Statement::call(expr("c ? f : g(a)")));
 }
 
 #[test]
@@ -152,12 +154,105 @@ fn test_statement_for() {
   );
 }
 
+#[test]
+fn test_statement_loop_control() {
+  assert_parse_statement!("break",    Statement::Break);
+  assert_parse_statement!("continue", Statement::Continue);
+  assert_parse_statement!("exit",     Statement::Exit);
+  assert_parse_statement!("breakable = 1", Statement::assignment(expr("breakable"), expr("1")));
+  assert_parse_statement!("exit_room()",   Statement::call(expr("exit_room()")));
+}
+
+#[test]
+fn test_statement_repeat() {
+  assert_parse_statement!(
+    "repeat (n + 1) {
+       spawn()
+     }",
+
+    Statement::repeat(
+      expr("(n + 1)"),
+      statement("{
+        spawn()
+      }")
+    )
+  );
+}
+
+#[test]
+fn test_statement_do_until() {
+  assert_parse_statement!(
+    "do {
+       i = i + 1
+     } until i > 10",
+
+    Statement::do_until(
+      statement("{
+        i = i + 1
+      }"),
+      expr("i > 10")
+    )
+  );
+}
+
+#[test]
+fn test_statement_switch() {
+  assert_parse_statement!(
+    "switch state {
+       case idle, walk {
+         move()
+       }
+       case jump {}
+       default {
+         fall()
+       }
+     }",
+
+    Statement::switch(
+      expr("state"),
+      &[
+        SwitchCase::new(&[expr("idle"), expr("walk")], statement("{
+          move()
+        }")),
+        SwitchCase::new(&[expr("jump")], statement("{}")),
+      ],
+      Some(statement("{
+        fall()
+      }"))
+    )
+  );
+
+  assert_parse_statement!(
+    "switch x {}",
+    Statement::switch(expr("x"), &[], None)
+  );
+}
+
 #[test]
 fn test_statement_assignment() {
   assert_parse_statement!("a = 2", Statement::assignment(expr("a"), expr("2")));
   assert_parse_statement!("g[0] = a + b", Statement::assignment(expr("g[0]"), expr("a + b")));
 }
 
+#[test]
+fn test_statement_compound_assignment() {
+  for op in ["+", "-", "*", "/", "%", "|", "&", "^"].iter() {
+    assert_parse_statement!(
+      &format!("hp {}= dmg", op),
+      Statement::compound_assignment(*op, expr("hp"), expr("dmg"))
+    );
+  }
+  assert_parse_statement!("a[i] -= b + 1", Statement::compound_assignment("-", expr("a[i]"), expr("b + 1")));
+}
+
+#[test]
+fn test_statement_increment() {
+  assert_parse_statement!("i++",   Statement::postfix(expr("i"), "++"));
+  assert_parse_statement!("i--",   Statement::postfix(expr("i"), "--"));
+  assert_parse_statement!("++i",   Statement::prefix("++", expr("i")));
+  assert_parse_statement!("--a.b", Statement::prefix("--", expr("a.b")));
+}
+
 #[test]
 fn test_statement_var() {
   assert_parse_statement!(
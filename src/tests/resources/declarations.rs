@@ -5,6 +5,9 @@ use crate::tests::utility::*;
 use crate::resources::resource_trait::*;
 use crate::resources::sprite::*;
 use crate::resources::sound::*;
+use crate::resources::font::*;
+use crate::resources::path::*;
+use crate::resources::background::*;
 use crate::resources::room::*;
 use crate::resources::script::*;
 use crate::resources::instance::*;
@@ -58,7 +61,7 @@ fn test_resources_sprite() {
   assert_eq!(s.texture.texture_group, 1);
 
   let e = Sprite::new(resource("sprite e { k: 1\n }"));
-  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
 }
 
 
@@ -93,7 +96,110 @@ fn test_resources_sound() {
   assert_eq!(s.audio_group, 1);
 
   let e = Sound::new(resource("sound e { k: 1\n }"));
-  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
+}
+
+#[test]
+fn test_resources_font() {
+  let f = Font::new(resource(r#"
+    font f {
+      name: "Courier New"
+      size: 16
+      bold: true
+      italic: true
+      antialiasing: 1
+      charset: 1
+      texture_group: 1
+
+      ranges[0].first: 48
+      ranges[0].last:  57
+      ranges[1].first: 65
+    }
+  "#)).unwrap();
+
+  assert_eq!(f.name, "Courier New");
+  assert_eq!(f.size, 16);
+  assert!(f.bold);
+  assert!(f.italic);
+  assert_eq!(f.antialiasing, 1);
+  assert_eq!(f.charset, 1);
+  assert_eq!(f.texture_group, 1);
+  assert_eq!(f.ranges, vec![CharacterRange { first: 48, last: 57 }, CharacterRange { first: 65, last: 127 }]);
+
+  let d = Font::new(resource("font d {}")).unwrap();
+  assert_eq!(d.character_ranges(), vec![CharacterRange { first: 32, last: 127 }]);
+
+  let e = Font::new(resource("font e { k: 1\n }"));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
+
+  // ranges are checked once all of their fields are set
+  let r = Font::new(resource("font r { ranges[0].last: 40\n ranges[0].first: 35\n }")).unwrap();
+  assert_eq!(r.ranges, vec![CharacterRange { first: 35, last: 40 }]);
+
+  let r = Font::new(resource("font r { ranges[0].first: 65\n ranges[1].first: 90\n ranges[1].last: 80\n }"));
+  assert_eq!(r, MglError::invalid_field("ranges", InvalidFieldKind::EmptyRange(ikey("ranges[1].first"))));
+}
+
+#[test]
+fn test_resources_path() {
+  let p = Path::new(resource(r#"
+    path p {
+      closed: true
+      smooth: true
+      precision: 8
+
+      points[0].x: 16
+      points[0].y: 32.5
+      points[0].speed: 50
+      points[1].x: 64
+    }
+  "#)).unwrap();
+
+  assert!(p.closed);
+  assert!(p.smooth);
+  assert_eq!(p.precision, 8);
+  assert_eq!(p.points, vec![
+    PathPoint { x: 16.0, y: 32.5, speed: 50.0 },
+    PathPoint { x: 64.0, y: 0.0,  speed: 100.0 },
+  ]);
+
+  let e = Path::new(resource("path e { k: 1\n }"));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
+}
+
+#[test]
+fn test_resources_background() {
+  let b = Background::new(resource(r#"
+    background b {
+      image: "images/tiles.png"
+      width: 64
+      height: 40
+      use_as_tileset: true
+      tile_width: 16
+      tile_height: 8
+      horizontal_offset: 2
+      vertical_offset: 1
+      horizontal_separation: 1
+      vertical_separation: 2
+      texture.texture_group: 1
+    }
+  "#)).unwrap();
+
+  assert_eq!(b.image, Some(PathBuf::from("images/tiles.png")));
+  assert_eq!(b.width, 64);
+  assert_eq!(b.height, 40);
+  assert!(b.use_as_tileset);
+  assert_eq!(b.tile_width, 16);
+  assert_eq!(b.tile_height, 8);
+  assert_eq!(b.horizontal_offset, 2);
+  assert_eq!(b.vertical_offset, 1);
+  assert_eq!(b.horizontal_separation, 1);
+  assert_eq!(b.vertical_separation, 2);
+  assert_eq!(b.texture.texture_group, 1);
+  assert_eq!(b.tile_count(), 3 * 4);
+
+  let e = Background::new(resource("background e { k: 1\n }"));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
 }
 
 #[test]
@@ -121,7 +227,7 @@ fn test_instance_sound() {
   assert_eq!(i.creation_code, Some(ResourceName::new(&["f"])));
 
   let e = Instance::new(instance("instance e of o { k: 1\n }"));
-  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
 }
 
 #[test]
@@ -136,9 +242,28 @@ fn test_resources_room() {
       clear_view_background: true
       clear_display_buffer: true
       color: 0
-      tiled: "rooms/r.tmx"
       instances[1]: obj::inst
 
+      backgrounds[0].background: sky
+      backgrounds[0].vtiled: false
+      backgrounds[0].hspeed: -2
+      backgrounds[1].background: clouds
+      backgrounds[1].foreground: true
+
+      tiles[0].background: ground
+      tiles[0].x: 32
+      tiles[0].y: 64
+      tiles[0].left: 16
+      tiles[0].depth: 10
+
+      views[1].view_w: 320
+      views[1].view_h: 240
+      views[1].port_x: 16
+      views[1].angle: 90
+      views[1].hborder: 64
+      views[1].hspeed: 4
+      views[1].follow: player
+
       instance i of o {
         x: 20
         y: 20
@@ -154,11 +279,47 @@ fn test_resources_room() {
   assert_eq!(r.clear_view_background, true);
   assert_eq!(r.clear_display_buffer, true);
   assert_eq!(r.color, 0);
-  assert_eq!(r.tiled, Some(PathBuf::from("rooms/r.tmx")));
-  assert_eq!(r.instances[1], InstanceItem::Unresolved(ResourceName::new(&["obj", "inst"])));
+  assert_eq!(r.instances[1], InstanceItem::Unresolved(AstDebugInfo::new(rn!(obj::inst))));
+
+  assert_eq!(r.backgrounds, vec![
+    RoomBackground { background: Some(rn!(sky)), vtiled: false, hspeed: -2, ..RoomBackground::default() },
+    RoomBackground { background: Some(rn!(clouds)), foreground: true, ..RoomBackground::default() },
+  ]);
+
+  assert_eq!(r.tiles, vec![
+    Tile { background: Some(rn!(ground)), x: 32, y: 64, left: 16, depth: 10, ..Tile::default() },
+  ]);
+
+  assert_eq!(r.views, vec![
+    View::default(),
+    View { view_w: 320, view_h: 240, port_x: 16, angle: 90.0, hborder: 64, hspeed: 4, follow: Some(rn!(player)), ..View::default() },
+  ]);
 
   let e = Room::new((resource("room e { k: 1\n }"), Vec::new()));
-  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound));
+  assert_eq!(e, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
+
+  // tiles need a background, including the ones left empty by a tile further in the list
+  let missing = |key: &str| MglError::invalid_field("tiles", InvalidFieldKind::MissingSubField(ikey(key), String::from("background")));
+
+  let t = Room::new((resource("room t { tiles[0].x: 4\n tiles[0].background: ground\n }"), Vec::new()));
+  assert_eq!(t.unwrap().tiles[0].background, Some(rn!(ground)));
+
+  let t = Room::new((resource("room t { tiles[0].x: 4\n }"), Vec::new()));
+  assert_eq!(t, missing("tiles[0].x"));
+
+  let t = Room::new((resource("room t { tiles[1].background: ground\n }"), Vec::new()));
+  assert_eq!(t, missing("tiles[1].background"));
+
+  // rooms have 8 backgrounds and 8 views
+  let v = Room::new((resource("room v { views[7].angle: 90\n creation_code: init\n }"), Vec::new())).unwrap();
+  assert_eq!(v.views.len(), 8);
+  assert_eq!(v.creation_code, Some(rn!(init)));
+
+  let v = Room::new((resource("room v { views[8].angle: 90\n }"), Vec::new()));
+  assert_eq!(v, MglError::invalid_field("views", InvalidFieldKind::OutOfRange(ikey("views[8].angle"), 8)));
+
+  let b = Room::new((resource("room b { backgrounds[9].visible: true\n }"), Vec::new()));
+  assert_eq!(b, MglError::invalid_field("backgrounds", InvalidFieldKind::OutOfRange(ikey("backgrounds[9].visible"), 8)));
 }
 
 #[test]
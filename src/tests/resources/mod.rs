@@ -2,4 +2,5 @@ mod resource_trait;
 mod declarations;
 mod object;
 mod project;
+mod wrapper;
 
@@ -15,11 +15,47 @@ fn test_resources_object_fields() {
 
   assert_eq!(o.sprite, Some(ResourceName::new(&["s"])));
   assert_eq!(o.persistent, true);
+  assert_eq!(o.parent, None);
+
+  let child = Object::new(resource("object c { parent: m::o\n }")).unwrap();
+  assert_eq!(child.parent.map(|p| p.content_clone()), Some(ResourceName::new(&["m", "o"])));
 
   let e1 = Object::new(resource("object e { k: 1\n }"));
   let e2 = Object::new(resource("object e { events: 1\n }"));
-  assert_eq!(e1, MglError::invalid_field("k",  InvalidFieldKind::NotFound));
-  assert_eq!(e2, MglError::invalid_field("events",  InvalidFieldKind::NotFound));
+  assert_eq!(e1, MglError::invalid_field("k",  InvalidFieldKind::NotFound(ikey("k"))));
+  assert_eq!(e2, MglError::invalid_field("events",  InvalidFieldKind::NotFound(ikey("events"))));
+}
+
+
+#[test]
+fn test_resources_object_physics() {
+  let o = Object::new(resource(r#"
+    object o {
+      mask: m
+      visible: false
+      solid: true
+      depth: -10
+      physics.enabled: true
+      physics.shape: "convex"
+      physics.density: 2
+      physics.shape_points[0].x: 1
+      physics.shape_points[1].y: 2
+    }
+  "#)).unwrap();
+
+  assert_eq!(o.mask, Some(ResourceName::new(&["m"])));
+  assert_eq!((o.visible, o.solid, o.depth), (false, true, -10));
+
+  assert_eq!(o.physics, Physics {
+    enabled: true,
+    shape: Some(PhysicsShape::Convex),
+    density: 2.0,
+    shape_points: vec![ShapePoint { x: 1.0, y: 0.0 }, ShapePoint { x: 0.0, y: 2.0 }],
+    ..Physics::default()
+  });
+
+  let e = Object::new(resource("object e { physics: 1\n }"));
+  assert_eq!(e, MglError::invalid_field("physics", InvalidFieldKind::NotSubResource(ikey("physics"))));
 }
 
 
@@ -326,23 +362,27 @@ fn test_resources_object_event_errors() {
     Object::new(resource(&code))
   };
 
-  let invalid_type = |ty: &str| {
-    InvalidIndexType(String::from(ty))
+  let invalid_type = |k: &str, ty: &str| {
+    MglError::event(InvalidIndexType(ikey(k), String::from(ty)))
+  };
+
+  let event = |k: &str, kind: fn(IKey) -> EventErrorKind| {
+    MglError::event(kind(ikey(k)))
   };
 
-  assert_eq!(err("a.b"),                   MglError::event(Dot));
-  assert_eq!(err("step[\"unknown\"]"),     MglError::event(UnknownStepKind));
-  assert_eq!(err("step[0]"),               MglError::event(invalid_type("string")));
-  assert_eq!(err("alarm[13]"),             MglError::event(UnknownAlarmKind));
-  assert_eq!(err("alarm[a]")  ,            MglError::event(invalid_type("number")));
-  assert_eq!(err("keyboard[\"unknown\"]"), MglError::event(UnknownKeyCode));
-  assert_eq!(err("keyboard[0]"),           MglError::event(invalid_type("string")));
-  assert_eq!(err("mouse[\"unknown\"]"),    MglError::event(UnknownMouseKind));
-  assert_eq!(err("mouse[0]"),              MglError::event(invalid_type("string")));
-  assert_eq!(err("collision[0]"),          MglError::event(invalid_type("resource name")));
-  assert_eq!(err("other[\"unknown\"]"),    MglError::event(UnknownOtherKind));
-  assert_eq!(err("other[0]"),              MglError::event(invalid_type("string")));
-  assert_eq!(err("draw[\"unknown\"]"),     MglError::event(UnknownDrawKind));
-  assert_eq!(err("draw[0]"),               MglError::event(invalid_type("string")));
+  assert_eq!(err("a.b"),                   event("a.b", Dot));
+  assert_eq!(err("step[\"unknown\"]"),     event("step[\"unknown\"]", UnknownStepKind));
+  assert_eq!(err("step[0]"),               invalid_type("step[0]", "string"));
+  assert_eq!(err("alarm[13]"),             event("alarm[13]", UnknownAlarmKind));
+  assert_eq!(err("alarm[a]")  ,            invalid_type("alarm[a]", "number"));
+  assert_eq!(err("keyboard[\"unknown\"]"), event("keyboard[\"unknown\"]", UnknownKeyCode));
+  assert_eq!(err("keyboard[0]"),           invalid_type("keyboard[0]", "string"));
+  assert_eq!(err("mouse[\"unknown\"]"),    event("mouse[\"unknown\"]", UnknownMouseKind));
+  assert_eq!(err("mouse[0]"),              invalid_type("mouse[0]", "string"));
+  assert_eq!(err("collision[0]"),          invalid_type("collision[0]", "resource name"));
+  assert_eq!(err("other[\"unknown\"]"),    event("other[\"unknown\"]", UnknownOtherKind));
+  assert_eq!(err("other[0]"),              invalid_type("other[0]", "string"));
+  assert_eq!(err("draw[\"unknown\"]"),     event("draw[\"unknown\"]", UnknownDrawKind));
+  assert_eq!(err("draw[0]"),               invalid_type("draw[0]", "string"));
 }
 
@@ -90,7 +90,7 @@ fn test_resources_project() {
   let room = Room::new((resource(&room1), vec![InstanceItem::Resolved(rins.unwrap())]));
 
   assert_eq!(project.objects[0], item(rn!(object::o),    obj));
-  assert_eq!(project.objects[1], item(rn!(wrapper::w),   wrap));
+  assert_eq!(project.objects[1], item(rn!(object::w),    wrap));
   assert_eq!(project.scripts[0], item(rn!(script::o::m), Ok(meth)));
   assert_eq!(project.scripts[1], item(rn!(script::f),    Ok(func)));
   assert_eq!(project.sprites[0], item(rn!(sprite::s),    spr));
@@ -107,10 +107,242 @@ fn test_resources_project_error() {
   let project = project(file1, file2);
 
   let err = MglError::InvalidField {
-    kind: InvalidFieldKind::NotFound,
-    field: String::from("unknown")
+    kind: InvalidFieldKind::NotFound(ikey("unknown")),
+    field: String::from("unknown"),
   };
 
   assert_eq!(project, Err(vec![err.clone(), err]));
 }
 
+
+
+#[test]
+fn test_resources_project_link_instances() {
+  let file1 = "
+    module m
+    object o {}
+    instance shared of o {}
+    instance other of o { x: 1\n }
+
+    room r {
+      instances[1]: shared
+      instances[2]: local
+      instances[4]: instance::m::other
+
+      instance local of o { x: 2\n }
+    }
+  ";
+
+  let mut linked = project(file1, "").unwrap();
+  linked.link_instances().unwrap();
+
+  let resolved = |name: &ResourceName| InstanceItem::Resolved(linked.instances[name].clone());
+
+  if let Item::File(_, room) = &linked.rooms[0] {
+    assert_eq!(room.instances[0], resolved(&rn!(instance::m::r::local)));
+    assert_eq!(room.instances[1], resolved(&rn!(instance::m::shared)));
+    assert_eq!(room.instances[2], resolved(&rn!(instance::m::r::local)));
+    assert_eq!(room.instances[3], InstanceItem::Empty);
+    assert_eq!(room.instances[4], resolved(&rn!(instance::m::other)));
+  } else {
+    unreachable!()
+  }
+
+  let mut unlinked = project("room r { instances[0]: missing\n }", "").unwrap();
+  let err = MglError::UnknownInstance { name: AstDebugInfo::new(rn!(missing)) };
+  assert_eq!(unlinked.link_instances(), Err(vec![err]));
+}
+
+
+#[test]
+fn test_resources_project_validate_references() {
+  let file1 = "
+    sprite player {}
+    path patrol {}
+    background sky {}
+    object hero {
+      sprite: playr
+      create: hero::on_create
+      collision[wall]: hero::bump
+
+      function bump() {}
+    }
+    instance h of heor { creation_code: setup\n }
+    room level {
+      backgrounds[0].background: skyy
+      views[0].follow: hreo
+    }
+  ";
+
+  let file2 = "
+    function setup() {
+      path_start(path::patrl, 4, path_action_stop, false)
+      path_start(path::patrol, 4, path_action_stop, false)
+    }
+  ";
+
+  let project = project(file1, file2).unwrap();
+
+  let unknown = |kind: &str, name: ResourceName, suggestion: Option<&str>| {
+    MglError::UnknownReference {
+      kind: String::from(kind),
+      name: AstDebugInfo::new(name),
+      suggestion: suggestion.map(String::from),
+    }
+  };
+
+  assert_eq!(
+    project.validate_references(),
+    Err(vec![
+      unknown("sprite", rn!(sprite::playr),           Some("sprite::player")),
+      unknown("script", rn!(script::hero::on_create), None),
+      unknown("object", rn!(object::wall),            None),
+      unknown("object", rn!(object::heor),            Some("object::hero")),
+      unknown("background", rn!(background::skyy),    Some("background::sky")),
+      unknown("object", rn!(object::hreo),            Some("object::hero")),
+      unknown("path",   rn!(path::patrl),             Some("path::patrol")),
+    ])
+  );
+}
+
+
+#[test]
+fn test_resources_project_link_methods() {
+  use crate::compiler::script::build_script;
+
+  let file1 = "
+    module m
+    object hero {
+      function jump(h) {}
+
+      function step() {
+        self.jump(4)
+        other.jump(speed + 1)
+        x = other.jump(2)
+        if ready && other.jump(1) {}
+      }
+    }
+  ";
+
+  let mut linked = project(file1, "").unwrap();
+  linked.link_methods().unwrap();
+
+  let step = find_file(&linked.scripts, &rn!(script::m::hero::step)).unwrap();
+
+  assert_eq!(build_script(step.clone()).unwrap().trim(), "
+m__hero__jump(4);
+var __mgl_argument0 = speed + 1;
+with other {
+    m__hero__jump(__mgl_argument0);
+}
+var __mgl_result1;
+with other {
+    __mgl_result1 = m__hero__jump(2);
+}
+x = __mgl_result1;
+var __mgl_and3 = false;
+if ready {
+    var __mgl_result2;
+    with other {
+        __mgl_result2 = m__hero__jump(1);
+    }
+    if __mgl_result2 {
+        __mgl_and3 = true;
+    }
+}
+if __mgl_and3 {
+}
+  ".trim());
+
+  let file1 = "
+    object o {
+      function f() {}
+      function g() { self.fo()\n }
+    }
+  ";
+
+  let mut unlinked = project(file1, "").unwrap();
+  let err = MglError::UnknownReference {
+    kind: String::from("method"),
+    name: AstDebugInfo::new(rn!(fo)),
+    suggestion: Some(String::from("f")),
+  };
+  assert_eq!(unlinked.link_methods(), Err(vec![err]));
+
+  // `other` is taken to be an instance of the calling object, not of whichever has the method
+  let file1 = "
+    object o {
+      function g() { other.land()\n }
+    }
+    object p {
+      function land() {}
+    }
+  ";
+
+  let mut unlinked = project(file1, "").unwrap();
+  let err = MglError::UnknownReference {
+    kind: String::from("method"),
+    name: AstDebugInfo::new(rn!(land)),
+    suggestion: None,
+  };
+  assert_eq!(unlinked.link_methods(), Err(vec![err]));
+}
+
+
+#[test]
+fn test_resources_project_inheritance() {
+  use crate::compiler::script::build_script;
+
+  let file1 = "
+    object enemy {
+      create: enemy::setup
+
+      function setup() {}
+      function hurt(n) {}
+      function die() {}
+    }
+
+    object boss {
+      parent: enemy
+      create: boss::setup
+
+      function setup() {
+        super()
+      }
+
+      function hurt(n) {
+        super(n / 2)
+        self.die()
+      }
+    }
+  ";
+
+  let mut linked = project(file1, "").unwrap();
+  linked.check_inheritance().unwrap();
+  linked.link_methods().unwrap();
+
+  let script = |name: ResourceName| build_script(find_file(&linked.scripts, &name).unwrap().clone()).unwrap();
+
+  assert_eq!(script(rn!(script::boss::setup)).trim(), "event_inherited();");
+  assert_eq!(script(rn!(script::boss::hurt)).trim(), "var n = argument0;\nenemy__hurt(n / 2);\nenemy__die();");
+
+  let file1 = "
+    object a { parent: b\n }
+    object b { parent: a\n }
+    object c { parent: a\n }
+  ";
+
+  let cyclic = project(file1, "function f() { super()\n }").unwrap();
+  let cycle  = ["a", "b", "a"].iter().map(|s| s.to_string()).collect();
+
+  assert_eq!(
+    cyclic.check_inheritance(),
+    Err(vec![MglError::Inheritance { kind: InheritanceErrorKind::Cycle(cycle), expression: expr("a") }])
+  );
+
+  let mut unlinked = cyclic;
+  assert_eq!(
+    unlinked.link_methods(),
+    Err(vec![MglError::Inheritance { kind: InheritanceErrorKind::SuperOutsideOverride, expression: expr("super") }])
+  );
+}
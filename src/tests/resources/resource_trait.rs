@@ -73,10 +73,10 @@ fn test_resources_trait_fail() {
   let err4 = resource(keys![sub_resource: 3]);
   let err5 = resource(keys![normal_field_1: "a"]);
 
-  assert_eq!(err1, MglError::invalid_field("unknown_field",  NotFound));
-  assert_eq!(err2, MglError::invalid_field("normal_field_1", NotSimple(key("normal_field_1[0]"))));
-  assert_eq!(err3, MglError::invalid_field("array_field",    NotArray(key("array_field"))));
-  assert_eq!(err4, MglError::invalid_field("sub_resource",   NotSubResource(key("sub_resource"))));
+  assert_eq!(err1, MglError::invalid_field("unknown_field",  NotFound(ikey("unknown_field"))));
+  assert_eq!(err2, MglError::invalid_field("normal_field_1", NotSimple(ikey("normal_field_1[0]"))));
+  assert_eq!(err3, MglError::invalid_field("array_field",    NotArray(ikey("array_field"))));
+  assert_eq!(err4, MglError::invalid_field("sub_resource",   NotSubResource(ikey("sub_resource"))));
   assert_eq!(err5, MglError::convert_expression(expr("\"a\""), "number (i64)"));
 }
 
@@ -0,0 +1,141 @@
+use crate::tests::utility::*;
+use crate::ast::*;
+use crate::error::*;
+use crate::event::Event;
+use crate::compiler::script::{build_script, build_script_for, GmlFeatures};
+use crate::resources::project::*;
+use crate::resources::object::*;
+
+fn linked(source: &str) -> Project {
+  let mut project = project_of(source);
+  project.link_methods().unwrap();
+  project.link_wrappers();
+  project
+}
+
+fn script(project: &Project, name: ResourceName) -> String {
+  build_script(find_file(&project.scripts, &name).unwrap().clone()).unwrap().trim().to_string()
+}
+
+const SOURCE: &str = "
+  object body {
+    create: body::setup
+
+    function setup() {}
+    function push(dx, dy) {}
+  }
+
+  object health {
+    destroy: health::drop
+
+    function drop() {}
+    function hurt(n) {}
+    function push(a, b) {}
+  }
+
+  wrapper player {
+    wraps[0]: body
+    wraps[1]: health
+    create: player::init
+
+    function init() {
+      self.hurt(1)
+    }
+  }
+";
+
+#[test]
+fn test_resources_wrapper_fields() {
+  let wrapper = Object::new(resource("wrapper w { wraps[0]: a\n wraps[2]: m::b\n }")).unwrap();
+  assert_eq!(wrapper.wrapped(), vec![rn!(object::a), rn!(object::m::b)]);
+
+  let object = Object::new(resource("object o { wraps[0]: a\n }"));
+  assert_eq!(object, MglError::invalid_field("wraps", InvalidFieldKind::NotFound(ikey("wraps[0]"))));
+}
+
+#[test]
+fn test_resources_wrapper_delegates() {
+  let project = linked(SOURCE);
+
+  for delegate in &[rn!(object::player::body), rn!(object::player::health)] {
+    let delegate = find_file(&project.objects, delegate).unwrap();
+    assert_eq!(delegate.events, vec![]);
+    assert_eq!(delegate.parent, None);
+  }
+
+  assert_eq!(
+    find_file(&project.objects, &rn!(object::player)).unwrap().events,
+    vec![
+      (Event::Create,  rn!(script::player::__mgl_event_0_0)),
+      (Event::Destroy, rn!(script::player::__mgl_event_1_0)),
+    ]
+  );
+
+  assert_eq!(script(&project, rn!(script::player::__mgl_event_0_0)), "
+__mgl_delegate_body = instance_create(x, y, object__player__body);
+__mgl_delegate_health = instance_create(x, y, object__player__health);
+with __mgl_delegate_body {
+    body__setup();
+}
+player__init();
+  ".trim());
+
+  let create = find_file(&project.scripts, &rn!(script::player::__mgl_event_0_0)).unwrap();
+  assert!(build_script_for(create.clone(), GmlFeatures::GMS2).unwrap().starts_with(
+    "__mgl_delegate_body = instance_create_depth(x, y, object_get_depth(object__player__body), object__player__body);"
+  ));
+
+  assert_eq!(script(&project, rn!(script::player::__mgl_event_1_0)), "
+with __mgl_delegate_body {
+    instance_destroy();
+}
+with __mgl_delegate_health {
+    health__drop();
+    instance_destroy();
+}
+  ".trim());
+}
+
+#[test]
+fn test_resources_wrapper_forwarding() {
+  let player = linked(SOURCE);
+
+  assert_eq!(script(&player, rn!(script::player::init)), "player__hurt(1);");
+
+  // the first wrapped object having a method wins
+  assert_eq!(script(&player, rn!(script::player::push)), "
+var dx = argument0;
+var dy = argument1;
+var __mgl_result;
+with __mgl_delegate_body {
+    __mgl_result = body__push(dx, dy);
+}
+return __mgl_result;
+  ".trim());
+
+  assert_eq!(script(&player, rn!(script::player::hurt)), "
+var n = argument0;
+var __mgl_result;
+with __mgl_delegate_health {
+    __mgl_result = health__hurt(n);
+}
+return __mgl_result;
+  ".trim());
+
+  // wrappers keep their own methods and the ones of their parent
+  let with_parent = linked("
+    object base { function push(x, y) {}\n }
+    object body { function push(dx, dy) {}\n }
+    wrapper w { parent: base\n wraps[0]: body\n }
+  ");
+
+  let forwarded = with_parent.scripts.iter().any(|item| match item {
+    Item::File(name, _) => name.clone() == rn!(script::w::push),
+    _ => false
+  });
+  assert!(!forwarded);
+  assert_eq!(script(&with_parent, rn!(script::w::__mgl_event_0_0)), "
+__mgl_delegate_body = instance_create(x, y, object__w__body);
+event_inherited();
+  ".trim());
+}
@@ -1,5 +1,7 @@
 use crate::ast::*;
 use crate::error::*;
+use crate::compiler::file_reader::AstFileTree;
+use crate::resources::project::{Project, Module, Item, Items};
 use std::str::FromStr;
 
 pub trait CloneAll<T> {
@@ -34,6 +36,31 @@ parse_unwrap_aliases! {
   resource    -> ResourceDeclaration;
 }
 
+pub fn ikey(code: &str) -> IKey {
+  IKey::new(key(code))
+}
+
+/// A project directory with a file for each piece of code, named `file0`, `file1`...
+pub fn file_tree(files: &[&str]) -> AstFileTree {
+  let leaves = files.iter().enumerate().map(|(i, code)| {
+    AstFileTree::Leaf(format!("file{}", i), code.parse().unwrap())
+  });
+  AstFileTree::Root(box AstFileTree::Node(String::new(), leaves.collect()))
+}
+
+pub fn project_of(source: &str) -> Project {
+  Project::from_ast_file_tree(file_tree(&[source]), Module::new()).unwrap()
+}
+
+/// The file called `name`, in a group or not.
+pub fn find_file<'a, T>(items: &'a Items<T>, name: &ResourceName) -> Option<&'a T> {
+  items.iter().find_map(|item| match item {
+    Item::File(file_name, file) if file_name == name => Some(file),
+    Item::Group(_, sub_items) => find_file(sub_items, name),
+    _ => None
+  })
+}
+
 pub macro resource($($arg: expr),*) {
   Declaration::Resource(ResourceDeclaration::new($($arg),*))
 }
@@ -0,0 +1,30 @@
+/// How many characters need to be inserted, removed or replaced to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+  for (i, ca) in a.chars().enumerate() {
+    let mut current = vec![i + 1];
+
+    for (j, cb) in b.iter().enumerate() {
+      let replace = previous[j] + if ca == *cb { 0 } else { 1 };
+      let insert  = current[j] + 1;
+      let remove  = previous[j + 1] + 1;
+      current.push(replace.min(insert).min(remove));
+    }
+    previous = current;
+  }
+  previous[b.len()]
+}
+
+/// The candidate closest to `name`, as long as it's close enough to be a typo.
+pub fn closest<'a, I: IntoIterator<Item = &'a str>>(name: &str, candidates: I) -> Option<&'a str> {
+  let length = name.chars().count();
+  let limit  = (length / 3).max(2);
+
+  candidates.into_iter()
+            .map(|candidate| (edit_distance(name, candidate), candidate))
+            .filter(|(distance, _)| *distance <= limit && *distance < length)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+}